@@ -0,0 +1,138 @@
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::{
+	field::Endian,
+	prelude::*,
+};
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+/// One mebibit of operand.
+const LEN: usize = 1 << 20;
+
+#[bench]
+fn bitwise_and(bench: &mut Bencher) {
+	let mut a = bitvec![Lsb0, usize; 1; LEN];
+	let b = bitvec![Lsb0, usize; 1; LEN];
+
+	bench.iter(|| *black_box(&mut a[..]) &= b.iter().by_val());
+}
+
+#[bench]
+fn lsb0_accel_and(bench: &mut Bencher) {
+	let mut a = bitvec![Lsb0, usize; 1; LEN];
+	let b = bitvec![Lsb0, usize; 1; LEN];
+
+	bench.iter(|| *black_box(&mut a[..]) &= &b[..]);
+}
+
+#[bench]
+fn msb0_accel_and(bench: &mut Bencher) {
+	let mut a = bitvec![Msb0, usize; 1; LEN];
+	let b = bitvec![Msb0, usize; 1; LEN];
+
+	bench.iter(|| *black_box(&mut a[..]) &= &b[..]);
+}
+
+#[bench]
+fn lsb0_accel_or_misaligned(bench: &mut Bencher) {
+	let mut a = bitvec![Lsb0, usize; 0; LEN];
+	let b = bitvec![Lsb0, usize; 0; LEN];
+
+	bench.iter(|| *black_box(&mut a[3 ..]) |= &b[11 ..]);
+}
+
+#[bench]
+fn msb0_accel_or_misaligned(bench: &mut Bencher) {
+	let mut a = bitvec![Msb0, usize; 0; LEN];
+	let b = bitvec![Msb0, usize; 0; LEN];
+
+	bench.iter(|| *black_box(&mut a[3 ..]) |= &b[11 ..]);
+}
+
+#[bench]
+fn lsb0_accel_xor_mixed_store(bench: &mut Bencher) {
+	let mut a = bitvec![Lsb0, u8; 0; LEN];
+	let b = bitvec![Lsb0, usize; 0; LEN];
+
+	bench.iter(|| *black_box(&mut a[5 ..]) ^= &b[.. LEN - 5]);
+}
+
+#[bench]
+fn not(bench: &mut Bencher) {
+	let mut a = bitvec![Lsb0, usize; 0; LEN];
+
+	bench.iter(|| {
+		!black_box(&mut a[3 ..]);
+	});
+}
+
+#[bench]
+fn chunked_load_le(bench: &mut Bencher) {
+	let bv = bitvec![Lsb0, u8; 1; 1 << 20];
+
+	bench.iter(|| {
+		black_box(&bv[3 ..])
+			.chunks(64)
+			.map(|chunk| chunk.load_le::<u64>())
+			.fold(0, |acc, word| acc ^ word)
+	});
+}
+
+#[bench]
+fn iter_words(bench: &mut Bencher) {
+	let bv = bitvec![Lsb0, u8; 1; 1 << 20];
+
+	bench.iter(|| {
+		black_box(&bv[3 ..])
+			.iter_words::<u64>()
+			.fold(0, |acc, word| acc ^ word)
+	});
+}
+
+#[bench]
+fn chunked_store_le(bench: &mut Bencher) {
+	let mut bv = bitvec![Lsb0, u8; 0; 1 << 20];
+
+	bench.iter(|| {
+		for chunk in black_box(&mut bv[3 ..]).chunks_mut(64) {
+			chunk.store_le(!0u64);
+		}
+	});
+}
+
+#[bench]
+fn store_words(bench: &mut Bencher) {
+	let mut bv = bitvec![Lsb0, u8; 0; 1 << 20];
+
+	bench.iter(|| {
+		black_box(&mut bv[3 ..]).store_words(core::iter::repeat(!0u64))
+	});
+}
+
+#[bench]
+fn chunked_load_fields(bench: &mut Bencher) {
+	let bv = bitvec![Msb0, u8; 1; 1 << 20];
+
+	bench.iter(|| {
+		black_box(&bv[..])
+			.chunks_exact(4)
+			.map(|chunk| chunk.load_be::<u16>())
+			.fold(0, |acc, field| acc ^ field)
+	});
+}
+
+#[bench]
+fn iter_fields(bench: &mut Bencher) {
+	let bv = bitvec![Msb0, u8; 1; 1 << 20];
+
+	bench.iter(|| {
+		black_box(&bv[..])
+			.iter_fields::<u16>(4, Endian::Big)
+			.fold(0, |acc, field| acc ^ field)
+	});
+}
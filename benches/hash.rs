@@ -0,0 +1,77 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::{
+	collections::hash_map::DefaultHasher,
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use bitvec::prelude::*;
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+/// One mebibit of key.
+const LEN: usize = 1 << 20;
+
+#[bench]
+fn bitwise_hash(bench: &mut Bencher) {
+	let bv = bitvec![Lsb0, usize; 1; LEN];
+
+	bench.iter(|| {
+		let mut hasher = DefaultHasher::new();
+		for bit in black_box(&bv[..]).iter().by_val() {
+			hasher.write_u8(bit as u8);
+		}
+		hasher.finish()
+	});
+}
+
+#[bench]
+fn lsb0_accel_hash(bench: &mut Bencher) {
+	let bv = bitvec![Lsb0, usize; 1; LEN];
+
+	bench.iter(|| {
+		let mut hasher = DefaultHasher::new();
+		black_box(&bv[..]).hash(&mut hasher);
+		hasher.finish()
+	});
+}
+
+#[bench]
+fn msb0_accel_hash(bench: &mut Bencher) {
+	let bv = bitvec![Msb0, usize; 1; LEN];
+
+	bench.iter(|| {
+		let mut hasher = DefaultHasher::new();
+		black_box(&bv[..]).hash(&mut hasher);
+		hasher.finish()
+	});
+}
+
+#[bench]
+fn lsb0_accel_hash_misaligned(bench: &mut Bencher) {
+	let bv = bitvec![Lsb0, u8; 1; LEN];
+
+	bench.iter(|| {
+		let mut hasher = DefaultHasher::new();
+		black_box(&bv[3 ..]).hash(&mut hasher);
+		hasher.finish()
+	});
+}
+
+#[bench]
+fn msb0_accel_hash_misaligned(bench: &mut Bencher) {
+	let bv = bitvec![Msb0, u8; 1; LEN];
+
+	bench.iter(|| {
+		let mut hasher = DefaultHasher::new();
+		black_box(&bv[3 ..]).hash(&mut hasher);
+		hasher.finish()
+	});
+}
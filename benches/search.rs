@@ -0,0 +1,92 @@
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::prelude::*;
+use test::{
+	bench::black_box,
+	Bencher,
+};
+
+/// One mebibit of haystack.
+const LEN: usize = 1 << 20;
+
+/// Irregular bytes, repeated to fill the haystack. The sync word does not occur
+/// anywhere in the repetition.
+const NOISE: [u8; 64] = [
+	0xE7, 0xE3, 0xA8, 0xEA, 0x0B, 0x28, 0x6C, 0x7F, 0xE0, 0xAB, 0xF9, 0x1C,
+	0x87, 0x19, 0x71, 0xE4, 0xB7, 0xA0, 0xB8, 0x58, 0x41, 0x4A, 0x17, 0x35,
+	0x36, 0x1C, 0x5B, 0xD8, 0xFA, 0x1F, 0xCE, 0x62, 0x8E, 0x67, 0xB6, 0x97,
+	0x2C, 0x97, 0x83, 0xEC, 0xC1, 0x28, 0x73, 0xBE, 0x33, 0x16, 0xF9, 0x0C,
+	0xD0, 0xDE, 0x7D, 0x9D, 0x85, 0x5E, 0x1F, 0x10, 0x6D, 0x91, 0x30, 0x50,
+	0x25, 0x97, 0xD8, 0x1F,
+];
+
+/// Builds an irregular haystack, with a 32-bit sync word placed at its end.
+fn haystack<O, T>() -> (BitVec<O, T>, BitVec)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let noise = NOISE.view_bits::<Lsb0>();
+	let mut bv = (0 .. LEN)
+		.map(|idx| noise[idx % noise.len()])
+		.collect::<BitVec<O, T>>();
+	let sync = 0x1ACF_FC1Du32.view_bits::<Lsb0>().to_bitvec();
+	bv[LEN - 32 ..].clone_from_bitslice(&sync);
+	(bv, sync.into_iter().collect())
+}
+
+#[bench]
+fn windows_find(bench: &mut Bencher) {
+	let (bv, sync) = haystack::<Lsb0, usize>();
+
+	bench.iter(|| {
+		black_box(&bv[..])
+			.windows(sync.len())
+			.position(|w| w == sync)
+	});
+}
+
+#[bench]
+fn lsb0_find(bench: &mut Bencher) {
+	let (bv, sync) = haystack::<Lsb0, usize>();
+
+	bench.iter(|| black_box(&bv[..]).find(&sync));
+}
+
+#[bench]
+fn msb0_find(bench: &mut Bencher) {
+	let (bv, sync) = haystack::<Msb0, u8>();
+
+	bench.iter(|| black_box(&bv[3 ..]).find(&sync));
+}
+
+#[bench]
+fn lsb0_rfind(bench: &mut Bencher) {
+	let (bv, sync) = haystack::<Lsb0, usize>();
+
+	bench.iter(|| black_box(&bv[.. LEN - 1]).rfind(&sync));
+}
+
+#[bench]
+fn bitwise_find_approx(bench: &mut Bencher) {
+	let (bv, sync) = haystack::<Lsb0, usize>();
+
+	bench.iter(|| {
+		black_box(&bv[..]).windows(sync.len()).position(|w| {
+			w.iter()
+				.by_val()
+				.zip(sync.iter().by_val())
+				.filter(|(a, b)| a != b)
+				.count() <= 3
+		})
+	});
+}
+
+#[bench]
+fn lsb0_find_approx(bench: &mut Bencher) {
+	let (bv, sync) = haystack::<Lsb0, usize>();
+
+	bench.iter(|| black_box(&bv[..]).find_approx(&sync, 3));
+}
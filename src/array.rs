@@ -58,6 +58,33 @@ This allows the structure to be parametric over the number of bits, rather than
 a scalar or array type that satisfies the number of bits. Unfortunately, it is
 inexpressible until the Rust compiler’s const-evaluation engine permits using
 numeric type parameters in type-level expressions.
+
+An exact bit length does not need that, if the buffer type remains a separate
+parameter:
+
+```rust
+struct BitArray<O, V, const N: usize>
+where
+  O: BitOrder,
+  V: BitView,
+{
+  _ord: PhantomData<O>,
+  data: V,
+}
+```
+
+`len()` would return `N`, and `as_bitslice()` would view only `data[.. N]`.
+Every trait that `BitArray` forwards to `BitSlice` (`Eq`, `Ord`, `Hash`,
+`Debug`, and iteration) would then ignore the dead bits at the end of `data`.
+`BitArr!(for N, in O, T)` would expand to
+`BitArray<O, [T; elts::<T>(N)], N>`, and `bitarr!` would build values of that
+type, so no user has to write the buffer size. A constructor would check
+`N <= V::const_bits()`, because the bound cannot be written in a `where` clause.
+
+This design needs `min_const_generics`, stabilized in Rust 1.51. This crate’s
+MSRV is 1.47, so the design waits for an MSRV increase. Until then, reborrow
+the live region instead: `&arr[.. N]` compares, hashes, and iterates only those
+`N` bits.
 */
 
 /** An array of individual bits, able to be held by value on the stack.
@@ -130,6 +157,33 @@ This type is useful for marking that some value is always to be used as a
 /// }
 /// ```
 /**
+# `const` Operations
+
+The trait system, and so most of the `BitArray` API, is unavailable in `const`
+contexts. Every `BitArray` of [`Lsb0`] or [`Msb0`] ordering over an array of
+one to thirty-two unsigned integers also has a set of `const fn` methods, named
+with a `const_` prefix: `const_new`, `const_zeroed`, `const_value`,
+`const_get`, `const_set`, `const_and`, `const_or`, `const_xor`, `const_not`, and
+`const_count_ones`. Those that modify the array take it by value and return the
+modified copy.
+**/
+///
+/// ```rust
+/// use bitvec::prelude::*;
+///
+/// type Reg = BitArr!(for 32, in Lsb0, u32);
+///
+/// const ENABLE: Reg = Reg::const_zeroed().const_set(0, true);
+/// const READY: Reg = Reg::const_zeroed().const_set(5, true);
+/// const MASK: Reg = ENABLE.const_or(READY);
+/// const UNUSED: usize = MASK.const_not().const_count_ones();
+///
+/// assert_eq!(MASK.const_value(), [0b10_0001]);
+/// assert!(MASK.const_get(5));
+/// assert_eq!(UNUSED, 30);
+/// assert_eq!(MASK.const_and(READY), READY);
+/// ```
+/**
 # Eventual Obsolescence
 
 When const-generics stabilize, this will be modified to have a signature more
@@ -139,6 +193,8 @@ behavior of ordinary arrays `[T; N]` as they stand today.
 [`BitSlice`]: crate::slice::BitSlice
 [`BitView`]: crate::view::BitView
 [`Deref`]: core::ops::Deref
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
 [`Mut`]: core::ops::DerefMut
 [`Sized`]: core::marker::Sized
 [`bitarr!`]: macro@crate::bitarr
@@ -277,6 +333,7 @@ where
 	}
 }
 
+mod consts;
 mod iter;
 mod ops;
 mod traits;
@@ -0,0 +1,170 @@
+/*! `const fn` operations on `BitArray`s of the integer storage types.
+
+The trait system cannot be used in `const` contexts, so the ordinary
+`BitArray` API, which is generic over its ordering and storage parameters, is
+unavailable when computing `const` and `static` values. This module provides a
+small set of inherent `const fn` methods on every concrete `BitArray` of
+`Lsb0` or `Msb0` ordering over an array of one to thirty-two unsigned integers.
+They are named with a `const_` prefix so that they do not shadow the ordinary
+methods, which `BitArray` borrows from `BitSlice`.
+
+The array lengths stop where the standard library’s trait implementations on
+arrays do. Each further length costs two impl blocks per storage type, and the
+full `0 ..= 64` range of `BitView` doubles the crate’s build time.
+
+Methods that modify the array take it by value and return the modified copy, as
+`const fn` cannot yet take `&mut` references.
+!*/
+
+use core::{
+	marker::PhantomData,
+	mem,
+};
+
+use crate::{
+	array::BitArray,
+	order::{
+		Lsb0,
+		Msb0,
+	},
+};
+
+//  Replace with a const-generic once that becomes available.
+macro_rules! const_ops {
+	($($t:ident),+ $(,)?) => { $(
+		const_ops!(@len $t;
+			1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+			20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+		);
+	)+ };
+
+	(@len $t:ident; $($n:literal),+ $(,)?) => { $(
+		const_ops!(@ord Lsb0, $t, $n, |bit| 1 << bit);
+		const_ops!(@ord Msb0, $t, $n, |bit| (1 << (BITS - 1)) >> bit);
+	)+ };
+
+	(@ord $o:ident, $t:ident, $n:literal, |$bit:ident| $mask:expr) => {
+		impl BitArray<$o, [$t; $n]> {
+			/// Wraps a buffer, in `const` contexts.
+			#[inline]
+			pub const fn const_new(data: [$t; $n]) -> Self {
+				Self {
+					_ord: PhantomData,
+					data,
+				}
+			}
+
+			/// Constructs an array with all bits cleared, in `const` contexts.
+			#[inline]
+			pub const fn const_zeroed() -> Self {
+				Self::const_new([0; $n])
+			}
+
+			/// Removes the wrapper, in `const` contexts.
+			#[inline]
+			pub const fn const_value(self) -> [$t; $n] {
+				self.data
+			}
+
+			/// Reads the bit at `index`, in `const` contexts.
+			///
+			/// # Panics
+			///
+			/// This panics, or fails `const` evaluation, if `index` is not less
+			/// than the bit length of the array.
+			#[inline]
+			pub const fn const_get(&self, index: usize) -> bool {
+				const BITS: usize = mem::size_of::<$t>() * 8;
+				let $bit = index % BITS;
+				let mask: $t = $mask;
+				self.data[index / BITS] & mask != 0
+			}
+
+			/// Writes `value` into the bit at `index`, in `const` contexts.
+			///
+			/// # Returns
+			///
+			/// The array, with the bit changed.
+			///
+			/// # Panics
+			///
+			/// This panics, or fails `const` evaluation, if `index` is not less
+			/// than the bit length of the array.
+			#[inline]
+			pub const fn const_set(self, index: usize, value: bool) -> Self {
+				const BITS: usize = mem::size_of::<$t>() * 8;
+				let $bit = index % BITS;
+				let mask: $t = $mask;
+				let mut data = self.data;
+				let elem = data[index / BITS];
+				data[index / BITS] = if value { elem | mask } else { elem & !mask };
+				Self::const_new(data)
+			}
+
+			/// Computes `self & rhs`, in `const` contexts.
+			#[inline]
+			pub const fn const_and(self, rhs: Self) -> Self {
+				let mut data = self.data;
+				let mut idx = 0;
+				while idx < $n {
+					data[idx] &= rhs.data[idx];
+					idx += 1;
+				}
+				Self::const_new(data)
+			}
+
+			/// Computes `self | rhs`, in `const` contexts.
+			#[inline]
+			pub const fn const_or(self, rhs: Self) -> Self {
+				let mut data = self.data;
+				let mut idx = 0;
+				while idx < $n {
+					data[idx] |= rhs.data[idx];
+					idx += 1;
+				}
+				Self::const_new(data)
+			}
+
+			/// Computes `self ^ rhs`, in `const` contexts.
+			#[inline]
+			pub const fn const_xor(self, rhs: Self) -> Self {
+				let mut data = self.data;
+				let mut idx = 0;
+				while idx < $n {
+					data[idx] ^= rhs.data[idx];
+					idx += 1;
+				}
+				Self::const_new(data)
+			}
+
+			/// Computes `!self`, in `const` contexts.
+			#[inline]
+			pub const fn const_not(self) -> Self {
+				let mut data = self.data;
+				let mut idx = 0;
+				while idx < $n {
+					data[idx] = !data[idx];
+					idx += 1;
+				}
+				Self::const_new(data)
+			}
+
+			/// Counts the set bits in the array, in `const` contexts.
+			#[inline]
+			pub const fn const_count_ones(&self) -> usize {
+				let mut count = 0;
+				let mut idx = 0;
+				while idx < $n {
+					count += self.data[idx].count_ones() as usize;
+					idx += 1;
+				}
+				count
+			}
+		}
+	};
+}
+
+const_ops!(u8, u16, u32, usize);
+
+#[cfg(target_pointer_width = "64")]
+const_ops!(u64);
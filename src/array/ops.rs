@@ -12,6 +12,10 @@ use core::ops::{
 	Index,
 	IndexMut,
 	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
 use crate::{
@@ -170,3 +174,67 @@ where
 		self
 	}
 }
+
+/// Shifts the contents towards index `0`, keeping the length unchanged.
+///
+/// See the [`BitSlice`] implementation for the effect under each ordering.
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+impl<O, V, Rhs> Shl<Rhs> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	BitSlice<O, V::Store>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+impl<O, V, Rhs> ShlAssign<Rhs> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	BitSlice<O, V::Store>: ShlAssign<Rhs>,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+/// Shifts the contents away from index `0`, keeping the length unchanged.
+///
+/// See the [`BitSlice`] implementation for the effect under each ordering.
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+impl<O, V, Rhs> Shr<Rhs> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	BitSlice<O, V::Store>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+impl<O, V, Rhs> ShrAssign<Rhs> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	BitSlice<O, V::Store>: ShrAssign<Rhs>,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
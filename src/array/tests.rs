@@ -134,6 +134,18 @@ fn ops() {
 
 	let _: &BitSlice = &*a;
 	let _: &mut BitSlice = &mut *f;
+
+	//  A 128-bit register, shifted across its element boundary.
+	let mut reg = bitarr![Msb0, u32; 0; 128];
+	reg.set(127, true);
+	reg <<= 64;
+	assert_eq!(reg.as_raw_slice(), [0, 1, 0, 0]);
+	let reg = reg << 63;
+	assert_eq!(reg.as_raw_slice(), [1 << 31, 0, 0, 0]);
+	let mut reg = reg >> 33;
+	assert_eq!(reg.as_raw_slice(), [0, 1 << 30, 0, 0]);
+	reg >>= 128;
+	assert!(reg.not_any());
 }
 
 #[test]
@@ -168,6 +180,32 @@ fn traits() {
 	assert!(a.not_any());
 }
 
+#[test]
+fn const_ops() {
+	const LSB: BitArray<Lsb0, [u8; 2]> =
+		BitArray::<Lsb0, [u8; 2]>::const_zeroed()
+			.const_set(1, true)
+			.const_set(9, true)
+			.const_set(15, true)
+			.const_set(15, false);
+	type M = BitArray<Msb0, [u16; 1]>;
+	const MSB: M = M::const_new([0xFF00]).const_xor(M::const_new([0x0FF0]));
+
+	assert_eq!(LSB.const_value(), [0b10, 0b10]);
+	assert!(LSB.iter_ones().eq([1, 9].iter().copied()));
+	assert_eq!(LSB.const_count_ones(), 2);
+	assert_eq!(LSB.const_not().const_count_ones(), 14);
+	for idx in 0 .. 16 {
+		assert_eq!(LSB.const_get(idx), LSB[idx]);
+		assert_eq!(MSB.const_get(idx), MSB[idx]);
+	}
+
+	assert_eq!(MSB.const_value(), [0xF0F0]);
+	assert_eq!(MSB[.. 4], bits![1; 4]);
+	assert_eq!(MSB.const_and(MSB.const_not()).const_count_ones(), 0);
+	assert_eq!(MSB.const_or(MSB.const_not()).const_count_ones(), 16);
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn format() {
@@ -14,6 +14,10 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
@@ -187,3 +191,67 @@ where
 		self
 	}
 }
+
+/// Shifts the contents towards index `0`, keeping the length unchanged.
+///
+/// See the [`BitSlice`] implementation for the effect under each ordering.
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+impl<O, T, Rhs> Shl<Rhs> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+impl<O, T, Rhs> ShlAssign<Rhs> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShlAssign<Rhs>,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+/// Shifts the contents away from index `0`, keeping the length unchanged.
+///
+/// See the [`BitSlice`] implementation for the effect under each ordering.
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+impl<O, T, Rhs> Shr<Rhs> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+impl<O, T, Rhs> ShrAssign<Rhs> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShrAssign<Rhs>,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
@@ -0,0 +1,904 @@
+/*! Compressed bitmap storage.
+
+A [`BitVec`] spends one bit of memory on every bit it holds, which is wasteful
+for sparse (or very dense) bitmaps that are written to disk or sent over a
+network. This module provides [`EwahBitmap`], which stores a bit-sequence in the
+*Enhanced Word-Aligned Hybrid* encoding: a stream of 64-bit words in which long
+runs of all-zero or all-one words are replaced by a count.
+
+The compressed form can be decoded back into a [`BitVec`] of any ordering and
+storage type, and also supports enumeration of its `1` bits, population counts,
+and the `&` and `|` operators, all without decompression.
+
+# Encoding
+
+The bitmap is divided into *logical words* of 64 bits. Logical word `n` holds
+bits `64 * n .. 64 * (n + 1)`, with bit index `i` stored at bit `i % 64` of the
+word, counted from its least significant end. A logical word that is entirely
+`0` or entirely `1` is *clean*; any other word is *dirty*.
+
+The encoded stream is a sequence of *markers*, each followed by the dirty
+words it announces. A marker word has three fields:
+
+|Bits      |Field                                                            |
+|:---------|:----------------------------------------------------------------|
+|`0`       |The value of every bit in the marker’s clean run.                |
+|`1 ..= 32`|The number of clean logical words in the run.                    |
+|`33 .. 64`|The number of dirty words that follow the marker, stored verbatim.|
+
+A marker’s clean run precedes its dirty words in the logical sequence. Bits past
+the end of the bitmap, in its final logical word, are always `0`.
+
+The stream stores no length of its own: a bitmap of 100 bits and a bitmap of 128
+bits may have the same words. Transmit [`.len()`] alongside [`.as_words()`], and
+use [`EwahBitmap::from_words`] to reassemble and validate them.
+
+[`BitVec`]: crate::vec::BitVec
+[`EwahBitmap`]: crate::compress::EwahBitmap
+[`EwahBitmap::from_words`]: crate::compress::EwahBitmap::from_words
+[`.as_words()`]: crate::compress::EwahBitmap::as_words
+[`.len()`]: crate::compress::EwahBitmap::len
+!*/
+
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	iter::FusedIterator,
+	ops::{
+		BitAnd,
+		BitOr,
+		Range,
+	},
+};
+
+use crate::{
+	mem,
+	order::BitOrder,
+	slice::{
+		BitOp,
+		BitSlice,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The number of bits in a logical word.
+const WORD_BITS: usize = 64;
+
+/// The position of the clean-run length field in a marker word.
+const RUN_SHIFT: u32 = 1;
+
+/// The greatest clean-run length that a marker word can hold.
+const MAX_RUN: u64 = (1 << 32) - 1;
+
+/// The position of the dirty-word count field in a marker word.
+const DIRTY_SHIFT: u32 = 33;
+
+/// The greatest dirty-word count that a marker word can hold.
+const MAX_DIRTY: u64 = (1 << 31) - 1;
+
+/** A bit-sequence stored in the EWAH compressed encoding.
+
+The [module documentation][crate::compress] describes the encoding. Bitmaps are
+always kept in one canonical form, so two `EwahBitmap`s are equal exactly when
+they hold the same bits.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::compress::EwahBitmap;
+
+let mut bits = bitvec![0; 100_000];
+bits.set(10, true);
+bits.set(70_000, true);
+
+let ewah = EwahBitmap::encode(&bits);
+assert_eq!(ewah.len(), 100_000);
+assert!(ewah.as_words().len() < 8);
+assert_eq!(ewah.iter_ones().collect::<Vec<_>>(), [10, 70_000]);
+assert_eq!(ewah.decode::<Lsb0, usize>(), bits);
+```
+**/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EwahBitmap {
+	/// The encoded stream.
+	words: Vec<u64>,
+	/// The number of bits in the bitmap.
+	len: usize,
+}
+
+impl EwahBitmap {
+	/// Compresses a bit-slice.
+	///
+	/// Runs of clean words are found with [`BitSlice::first_one`], so sparse
+	/// regions are skipped a memory element at a time rather than bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The bits to compress.
+	///
+	/// # Returns
+	///
+	/// A compressed bitmap holding the same bits as `bits`.
+	///
+	/// [`BitSlice::first_one`]: crate::slice::BitSlice::first_one
+	pub fn encode<O, T>(bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let total = mem::elts::<u64>(bits.len());
+		let mut out = Builder::default();
+		let mut word = 0;
+
+		while word < total {
+			let rest = &bits[word * WORD_BITS ..];
+			let skip = match rest.first_one() {
+				Some(idx) => idx / WORD_BITS,
+				None => {
+					out.push_clean(false, total - word);
+					break;
+				},
+			};
+			out.push_clean(false, skip);
+			word += skip;
+
+			let chunk = &rest[skip * WORD_BITS ..];
+			let chunk = &chunk[.. cmp::min(WORD_BITS, chunk.len())];
+			if chunk.len() == WORD_BITS && chunk.all() {
+				out.push_clean(true, 1);
+			}
+			else {
+				out.push_word(
+					chunk.iter_ones().fold(0, |acc, idx| acc | 1 << idx),
+				);
+			}
+			word += 1;
+		}
+
+		out.finish(bits.len())
+	}
+
+	/// Decompresses the bitmap into a new bit-vector.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: The ordering of the produced bit-vector.
+	/// - `T`: The storage type of the produced bit-vector.
+	///
+	/// # Returns
+	///
+	/// A bit-vector of [`.len()`] bits, holding the same bits as `self`.
+	///
+	/// [`.len()`]: Self::len
+	pub fn decode<O, T>(&self) -> BitVec<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut out = BitVec::with_capacity(self.len);
+		let mut cursor = self.cursor();
+		while let Some(chunk) = cursor.peek() {
+			let start = out.len();
+			match chunk {
+				Chunk::Clean(bit, count) => {
+					let end =
+						count.saturating_mul(WORD_BITS).saturating_add(start);
+					out.resize(cmp::min(end, self.len), bit);
+					cursor.advance(count);
+				},
+				Chunk::Dirty(mut word) => {
+					out.resize(cmp::min(start + WORD_BITS, self.len), false);
+					while word != 0 {
+						out.set(start + word.trailing_zeros() as usize, true);
+						word &= word - 1;
+					}
+					cursor.advance(1);
+				},
+			}
+		}
+		out
+	}
+
+	/// Reassembles a bitmap from its encoded words and its length.
+	///
+	/// This is the inverse of taking [`.as_words()`] and [`.len()`] from an
+	/// existing bitmap. The words are checked for consistency, and re-packed
+	/// into the canonical form if another encoder produced them.
+	///
+	/// # Parameters
+	///
+	/// - `words`: An EWAH-encoded word stream.
+	/// - `len`: The number of bits the stream describes.
+	///
+	/// # Returns
+	///
+	/// The bitmap, if the stream is well-formed and describes exactly `len`
+	/// bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::compress::{EwahBitmap, EwahError};
+	///
+	/// let ewah = EwahBitmap::encode(bits![1; 200]);
+	/// let words = ewah.as_words().to_vec();
+	///
+	/// assert_eq!(EwahBitmap::from_words(words.clone(), 200), Ok(ewah));
+	/// assert_eq!(
+	///   EwahBitmap::from_words(words, 300),
+	///   Err(EwahError::Length { words: 4, expected: 5 }),
+	/// );
+	/// ```
+	///
+	/// [`.as_words()`]: Self::as_words
+	/// [`.len()`]: Self::len
+	pub fn from_words(words: Vec<u64>, len: usize) -> Result<Self, EwahError> {
+		let mut idx = 0;
+		let mut count = 0usize;
+		let mut last = None;
+		while let Some(&marker) = words.get(idx) {
+			let (bit, run, dirty) = unpack(marker);
+			idx += 1;
+			if dirty > words.len() - idx {
+				return Err(EwahError::Truncated);
+			}
+			idx += dirty;
+			count = count.saturating_add(run).saturating_add(dirty);
+			if dirty > 0 {
+				last = Some(Chunk::Dirty(words[idx - 1]));
+			}
+			else if run > 0 {
+				last = Some(Chunk::Clean(bit, run));
+			}
+		}
+
+		let expected = mem::elts::<u64>(len);
+		if count != expected {
+			return Err(EwahError::Length {
+				words: count,
+				expected,
+			});
+		}
+		let live = len % WORD_BITS;
+		if live != 0 {
+			let padded = match last {
+				Some(Chunk::Clean(bit, _)) => bit,
+				Some(Chunk::Dirty(word)) => word >> live != 0,
+				None => false,
+			};
+			if padded {
+				return Err(EwahError::Padding);
+			}
+		}
+
+		let mut out = Builder::default();
+		let mut cursor = Cursor::new(&words);
+		while let Some(chunk) = cursor.peek() {
+			match chunk {
+				Chunk::Clean(bit, count) => {
+					out.push_clean(bit, count);
+					cursor.advance(count);
+				},
+				Chunk::Dirty(word) => {
+					out.push_word(word);
+					cursor.advance(1);
+				},
+			}
+		}
+		Ok(out.finish(len))
+	}
+
+	/// Gets the number of bits in the bitmap.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests whether the bitmap holds no bits.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Views the encoded word stream.
+	///
+	/// The stream does not record [`.len()`], which must be stored alongside
+	/// it in order to reassemble the bitmap with [`from_words`].
+	///
+	/// [`.len()`]: Self::len
+	/// [`from_words`]: Self::from_words
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn as_words(&self) -> &[u64] {
+		&self.words
+	}
+
+	/// Counts the bits in the bitmap set to `1`.
+	///
+	/// This reads only the marker and dirty words of the encoding; clean runs
+	/// are counted by their length.
+	pub fn count_ones(&self) -> usize {
+		let mut cursor = self.cursor();
+		let mut done = 0usize;
+		let mut ones = 0;
+		while let Some(chunk) = cursor.peek() {
+			match chunk {
+				Chunk::Clean(bit, count) => {
+					let bits = count.saturating_mul(WORD_BITS);
+					if bit {
+						ones += cmp::min(bits, self.len - done);
+					}
+					done = done.saturating_add(bits);
+					cursor.advance(count);
+				},
+				Chunk::Dirty(word) => {
+					ones += word.count_ones() as usize;
+					done += WORD_BITS;
+					cursor.advance(1);
+				},
+			}
+		}
+		ones
+	}
+
+	/// Counts the bits in the bitmap set to `0`.
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.len - self.count_ones()
+	}
+
+	/// Enumerates the indices of the bits set to `1`, in ascending order,
+	/// directly from the compressed form.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::compress::EwahBitmap;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1];
+	/// let ewah = EwahBitmap::encode(bits);
+	/// assert!(ewah.iter_ones().eq(bits.iter_ones()));
+	/// ```
+	#[inline]
+	pub fn iter_ones(&self) -> IterOnes {
+		IterOnes {
+			cursor: self.cursor(),
+			len: self.len,
+			next_word: 0,
+			dirty: 0,
+			dirty_base: 0,
+			run: 0 .. 0,
+		}
+	}
+
+	/// Combines two bitmaps word by word, without decompressing either.
+	///
+	/// The result is as long as the longer operand, and the shorter operand
+	/// is treated as if it were extended with `0` bits. A clean run that
+	/// determines the result by itself (`0` for `&`, `1` for `|`) is copied
+	/// into the result as a run, and skips over the other operand’s words.
+	fn merge(&self, other: &Self, op: BitOp) -> Self {
+		let len = cmp::max(self.len, other.len);
+		let total = mem::elts::<u64>(len);
+		let mut lhs = self.cursor();
+		let mut rhs = other.cursor();
+		let mut out = Builder::default();
+		let mut done = 0;
+
+		//  An exhausted operand continues as a run of `0` words.
+		let tail = Chunk::Clean(false, usize::MAX);
+		while done < total {
+			let step =
+				match (lhs.peek().unwrap_or(tail), rhs.peek().unwrap_or(tail)) {
+					(Chunk::Clean(a, m), Chunk::Clean(b, n)) => {
+						let step = cmp::min(cmp::min(m, n), total - done);
+						out.push_clean(op.apply(a, b), step);
+						step
+					},
+					(Chunk::Clean(a, m), Chunk::Dirty(word)) => {
+						if op.apply(a, false) == op.apply(a, true) {
+							let step = cmp::min(m, total - done);
+							out.push_clean(op.apply(a, false), step);
+							step
+						}
+						else {
+							out.push_word(op.apply(spread(a), word));
+							1
+						}
+					},
+					(Chunk::Dirty(word), Chunk::Clean(b, n)) => {
+						if op.apply(false, b) == op.apply(true, b) {
+							let step = cmp::min(n, total - done);
+							out.push_clean(op.apply(false, b), step);
+							step
+						}
+						else {
+							out.push_word(op.apply(word, spread(b)));
+							1
+						}
+					},
+					(Chunk::Dirty(a), Chunk::Dirty(b)) => {
+						out.push_word(op.apply(a, b));
+						1
+					},
+				};
+			lhs.advance(step);
+			rhs.advance(step);
+			done += step;
+		}
+
+		out.finish(len)
+	}
+
+	/// Begins a read of the encoded stream.
+	#[inline]
+	fn cursor(&self) -> Cursor {
+		Cursor::new(&self.words)
+	}
+}
+
+impl<O, T> From<&BitSlice<O, T>> for EwahBitmap
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	fn from(bits: &BitSlice<O, T>) -> Self {
+		Self::encode(bits)
+	}
+}
+
+impl<O, T> From<&EwahBitmap> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	fn from(ewah: &EwahBitmap) -> Self {
+		ewah.decode()
+	}
+}
+
+/** Computes the intersection of two compressed bitmaps.
+
+The result is as long as the longer operand; bits past the end of the shorter
+operand are treated as `0`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::compress::EwahBitmap;
+
+let a = EwahBitmap::encode(bits![1, 1, 0, 0, 1]);
+let b = EwahBitmap::encode(bits![1, 0, 1]);
+assert_eq!((&a & &b).decode::<Lsb0, u8>(), bits![1, 0, 0, 0, 0]);
+```
+**/
+impl BitAnd for &EwahBitmap {
+	type Output = EwahBitmap;
+
+	#[inline]
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.merge(rhs, BitOp::And)
+	}
+}
+
+/** Computes the union of two compressed bitmaps.
+
+The result is as long as the longer operand; bits past the end of the shorter
+operand are treated as `0`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::compress::EwahBitmap;
+
+let a = EwahBitmap::encode(bits![1, 1, 0, 0, 1]);
+let b = EwahBitmap::encode(bits![1, 0, 1]);
+assert_eq!((&a | &b).decode::<Lsb0, u8>(), bits![1, 1, 1, 0, 1]);
+```
+**/
+impl BitOr for &EwahBitmap {
+	type Output = EwahBitmap;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.merge(rhs, BitOp::Or)
+	}
+}
+
+/** Enumerates the bits of an [`EwahBitmap`] that are set to `1`.
+
+This struct is created by the [`.iter_ones()`] method on [`EwahBitmap`]s.
+
+[`EwahBitmap`]: crate::compress::EwahBitmap
+[`.iter_ones()`]: crate::compress::EwahBitmap::iter_ones
+**/
+#[derive(Clone, Debug)]
+pub struct IterOnes<'a> {
+	/// The unread remainder of the encoded stream.
+	cursor: Cursor<'a>,
+	/// The number of bits in the bitmap.
+	len: usize,
+	/// The bit index at which the next unread logical word begins.
+	next_word: usize,
+	/// The unreported `1` bits of the current dirty word.
+	dirty: u64,
+	/// The bit index at which the current dirty word begins.
+	dirty_base: usize,
+	/// The unreported indices of the current clean run of `1` bits.
+	run: Range<usize>,
+}
+
+impl Iterator for IterOnes<'_> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(idx) = self.run.next() {
+				return Some(idx);
+			}
+			if self.dirty != 0 {
+				let idx = self.dirty_base + self.dirty.trailing_zeros() as usize;
+				self.dirty &= self.dirty - 1;
+				return Some(idx);
+			}
+			match self.cursor.peek()? {
+				Chunk::Clean(bit, count) => {
+					let end = count
+						.saturating_mul(WORD_BITS)
+						.saturating_add(self.next_word);
+					if bit {
+						self.run = self.next_word .. cmp::min(end, self.len);
+					}
+					self.next_word = end;
+					self.cursor.advance(count);
+				},
+				Chunk::Dirty(word) => {
+					self.dirty = word;
+					self.dirty_base = self.next_word;
+					self.next_word += WORD_BITS;
+					self.cursor.advance(1);
+				},
+			}
+		}
+	}
+}
+
+impl FusedIterator for IterOnes<'_> {
+}
+
+/// Reasons that a word stream is not a valid [`EwahBitmap`].
+///
+/// [`EwahBitmap`]: crate::compress::EwahBitmap
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EwahError {
+	/// A marker word announces more dirty words than remain in the stream.
+	Truncated,
+	/// The stream describes a different number of logical words than the
+	/// bitmap length requires.
+	Length {
+		/// The number of logical words the stream describes.
+		words: usize,
+		/// The number of logical words the bitmap length requires.
+		expected: usize,
+	},
+	/// Bits past the end of the bitmap, in its final logical word, are set.
+	Padding,
+}
+
+impl Display for EwahError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Truncated => fmt.write_str(
+				"EWAH stream ends inside the dirty words of its last marker",
+			),
+			Self::Length { words, expected } => write!(
+				fmt,
+				"EWAH stream describes {} words, but the bitmap needs {}",
+				words, expected,
+			),
+			Self::Padding => {
+				fmt.write_str("EWAH stream sets bits past the end of the bitmap")
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EwahError {
+}
+
+/// One step of a decoded EWAH stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Chunk {
+	/// Some number of logical words with every bit set to the same value.
+	Clean(bool, usize),
+	/// One logical word, stored verbatim.
+	Dirty(u64),
+}
+
+/// Reads an EWAH stream one logical word, or one clean run, at a time.
+#[derive(Clone, Debug)]
+struct Cursor<'a> {
+	/// The dirty words of the current marker, followed by all later markers.
+	words: &'a [u64],
+	/// The value of the current clean run.
+	bit: bool,
+	/// The number of logical words remaining in the current clean run.
+	run: usize,
+	/// The number of dirty words remaining under the current marker.
+	dirty: usize,
+}
+
+impl<'a> Cursor<'a> {
+	/// Begins a read at the first marker of a stream.
+	fn new(words: &'a [u64]) -> Self {
+		let mut out = Self {
+			words,
+			bit: false,
+			run: 0,
+			dirty: 0,
+		};
+		out.refill();
+		out
+	}
+
+	/// Views the next chunk without consuming it.
+	///
+	/// A clean run is reported in full, and may be partially consumed by
+	/// [`advance`].
+	///
+	/// [`advance`]: Self::advance
+	fn peek(&self) -> Option<Chunk> {
+		if self.run > 0 {
+			Some(Chunk::Clean(self.bit, self.run))
+		}
+		else if self.dirty > 0 {
+			self.words.first().copied().map(Chunk::Dirty)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Consumes `count` logical words, crossing marker boundaries as needed.
+	fn advance(&mut self, mut count: usize) {
+		while count > 0 && (self.run > 0 || self.dirty > 0) {
+			if self.run > 0 {
+				let step = cmp::min(count, self.run);
+				self.run -= step;
+				count -= step;
+			}
+			else {
+				let step = cmp::min(count, self.dirty);
+				self.dirty -= step;
+				self.words = self.words.get(step ..).unwrap_or(&[]);
+				count -= step;
+			}
+			self.refill();
+		}
+	}
+
+	/// Loads markers until one with a non-empty run or dirty words is found.
+	fn refill(&mut self) {
+		while self.run == 0 && self.dirty == 0 {
+			let (&marker, rest) = match self.words.split_first() {
+				Some(pair) => pair,
+				None => return,
+			};
+			let (bit, run, dirty) = unpack(marker);
+			self.words = rest;
+			self.bit = bit;
+			self.run = run;
+			self.dirty = cmp::min(dirty, rest.len());
+		}
+	}
+}
+
+/// Accumulates logical words into the canonical EWAH encoding.
+#[derive(Clone, Debug, Default)]
+struct Builder {
+	/// The encoded stream.
+	words: Vec<u64>,
+	/// The index of the marker that is currently accepting words.
+	marker: usize,
+}
+
+impl Builder {
+	/// Appends `count` clean words whose bits are all `bit`.
+	fn push_clean(&mut self, bit: bool, mut count: usize) {
+		while count > 0 {
+			let (mbit, run, dirty) = self.current();
+			//  A run can only grow if no dirty words follow it yet.
+			if dirty == 0 && (run == 0 || mbit == bit) && (run as u64) < MAX_RUN
+			{
+				let step = cmp::min(count as u64, MAX_RUN - run as u64);
+				self.words[self.marker] = pack(bit, run + step as usize, 0);
+				count -= step as usize;
+			}
+			else {
+				self.open();
+			}
+		}
+	}
+
+	/// Appends one logical word, as a clean run if it is clean.
+	fn push_word(&mut self, word: u64) {
+		match word {
+			0 => self.push_clean(false, 1),
+			w if w == !0 => self.push_clean(true, 1),
+			_ => {
+				let (mut bit, mut run, mut dirty) = self.current();
+				if dirty as u64 == MAX_DIRTY {
+					self.open();
+					bit = false;
+					run = 0;
+					dirty = 0;
+				}
+				self.words[self.marker] = pack(bit, run, dirty + 1);
+				self.words.push(word);
+			},
+		}
+	}
+
+	/// Finishes the stream as a bitmap of `len` bits.
+	fn finish(self, len: usize) -> EwahBitmap {
+		EwahBitmap {
+			words: self.words,
+			len,
+		}
+	}
+
+	/// Unpacks the current marker, creating the first one if necessary.
+	fn current(&mut self) -> (bool, usize, usize) {
+		if self.words.is_empty() {
+			self.open();
+		}
+		unpack(self.words[self.marker])
+	}
+
+	/// Starts a new, empty, marker at the end of the stream.
+	fn open(&mut self) {
+		self.marker = self.words.len();
+		self.words.push(0);
+	}
+}
+
+/// Assembles a marker word from its fields.
+fn pack(bit: bool, run: usize, dirty: usize) -> u64 {
+	bit as u64 | (run as u64) << RUN_SHIFT | (dirty as u64) << DIRTY_SHIFT
+}
+
+/// Splits a marker word into its fields.
+fn unpack(marker: u64) -> (bool, usize, usize) {
+	(
+		marker & 1 != 0,
+		(marker >> RUN_SHIFT & MAX_RUN) as usize,
+		(marker >> DIRTY_SHIFT & MAX_DIRTY) as usize,
+	)
+}
+
+/// Expands a bit into a clean word.
+fn spread(bit: bool) -> u64 {
+	if bit { !0 } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(not(feature = "std"))]
+	use alloc::vec;
+
+	use super::*;
+	use crate::prelude::*;
+
+	/// Builds a bit-vector with a mix of sparse, dense, and clean regions.
+	fn sample(len: usize, seed: usize) -> BitVec<Lsb0, u8> {
+		(0 .. len)
+			.map(|idx| match (idx + seed) / 500 % 4 {
+				0 => false,
+				1 => true,
+				2 => idx % 37 == seed % 37,
+				_ => idx % 3 != 0,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn round_trip() {
+		assert!(EwahBitmap::encode(bits![]).as_words().is_empty());
+		assert_eq!(EwahBitmap::default().decode::<Lsb0, u8>(), bits![]);
+
+		for &len in &[1, 63, 64, 65, 640, 2000, 4097] {
+			for seed in 0 .. 3 {
+				let bits = sample(len, seed * 250);
+				let ewah = EwahBitmap::encode(&bits);
+
+				assert_eq!(ewah.len(), len);
+				assert_eq!(ewah.decode::<Lsb0, u8>(), bits);
+				assert_eq!(ewah.decode::<Msb0, u32>(), bits);
+				assert_eq!(ewah.count_ones(), bits.count_ones());
+				assert!(ewah.iter_ones().eq(bits.iter_ones()));
+				assert_eq!(
+					EwahBitmap::from_words(ewah.as_words().to_vec(), len),
+					Ok(ewah.clone()),
+				);
+
+				//  Encoding from a misaligned region of another ordering
+				//  produces the same canonical stream.
+				let mut other = bitvec![Msb0, u16; 0; 3];
+				other.extend(bits.iter().by_val());
+				assert_eq!(EwahBitmap::encode(&other[3 ..]), ewah);
+			}
+		}
+
+		let ones = EwahBitmap::encode(bits![1; 1000]);
+		assert_eq!(ones.as_words().len(), 2);
+		assert_eq!(ones.count_ones(), 1000);
+		assert_eq!(ones.iter_ones().count(), 1000);
+	}
+
+	#[test]
+	fn operators() {
+		for &(la, lb) in &[(0, 100), (64, 64), (1000, 700), (2000, 4097)] {
+			let a = sample(la, 0);
+			let b = sample(lb, 300);
+			let (ea, eb) = (EwahBitmap::encode(&a), EwahBitmap::encode(&b));
+
+			assert_eq!((&ea & &eb).decode::<Lsb0, u8>(), &a & &b);
+			assert_eq!((&ea | &eb).decode::<Lsb0, u8>(), &a | &b);
+			assert_eq!(&ea & &eb, EwahBitmap::encode(&(&a & &b)));
+			assert_eq!(&eb | &ea, EwahBitmap::encode(&(&a | &b)));
+		}
+	}
+
+	#[test]
+	fn validation() {
+		let ewah = EwahBitmap::encode(&sample(1000, 0));
+		let words = ewah.as_words().to_vec();
+
+		assert_eq!(
+			EwahBitmap::from_words(words[.. words.len() - 1].to_vec(), 1000),
+			Err(EwahError::Truncated),
+		);
+		assert_eq!(
+			EwahBitmap::from_words(words, 2000),
+			Err(EwahError::Length {
+				words: 16,
+				expected: 32
+			}),
+		);
+		assert_eq!(
+			EwahBitmap::from_words(vec![pack(true, 2, 0)], 100),
+			Err(EwahError::Padding),
+		);
+		assert_eq!(
+			EwahBitmap::from_words(vec![pack(false, 0, 1), 1 << 40], 20),
+			Err(EwahError::Padding),
+		);
+
+		//  Non-canonical streams are accepted and re-packed.
+		let split =
+			vec![pack(true, 1, 0), pack(true, 1, 1), 5, pack(false, 0, 0)];
+		let ewah = EwahBitmap::from_words(split, 192).unwrap();
+		assert_eq!(ewah.as_words(), &[pack(true, 2, 1), 5]);
+		assert_eq!(ewah.count_ones(), 130);
+	}
+}
@@ -111,6 +111,20 @@ where
 	match_order::<O1, O2>() && TypeId::of::<T1>() == TypeId::of::<T2>()
 }
 
+/// Produces a reproducible stream of irregular words, for test data.
+///
+/// This is Marsaglia’s xorshift64 generator; `seed` must not be zero.
+#[cfg(test)]
+pub fn xorshift(seed: u64) -> impl Iterator<Item = u64> {
+	let mut state = seed;
+	core::iter::repeat_with(move || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state
+	})
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
 	use std::panic::catch_unwind;
@@ -205,12 +205,16 @@ use core::{
 	ptr,
 };
 
-use funty::IsNumber;
+use funty::{
+	IsInteger,
+	IsNumber,
+};
 use tap::pipe::Pipe;
 
 use crate::{
 	access::BitAccess,
 	array::BitArray,
+	devel as dvl,
 	domain::{
 		Domain,
 		DomainMut,
@@ -1436,7 +1440,7 @@ where
 ///
 /// [`M::BITS`]: funty::IsNumber::BITS
 fn check<M>(action: &'static str, len: usize)
-where M: BitMemory {
+where M: IsInteger {
 	if !(1 ..= M::BITS as usize).contains(&len) {
 		panic!(
 			"Cannot {} {} bits from a {}-bit region",
@@ -1447,6 +1451,91 @@ where M: BitMemory {
 	}
 }
 
+/// Loads a region so that index `k` of the region is bit `k` of the result.
+///
+/// Word-parallel kernels use this layout to shift and compare bit-slices
+/// without regard to their ordering. [`Lsb0`] regions load directly, [`Msb0`]
+/// regions load big-endian and reverse, and any other ordering is collected
+/// one bit at a time.
+///
+/// # Panics
+///
+/// This panics if `bits` is empty, or wider than [`M::BITS`].
+///
+/// [`Lsb0`]: crate::order::Lsb0
+/// [`M::BITS`]: funty::IsNumber::BITS
+/// [`Msb0`]: crate::order::Msb0
+#[inline]
+pub(crate) fn load_indexed<O, T, M>(bits: &BitSlice<O, T>) -> M
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	check::<M>("load", bits.len());
+	if dvl::match_order::<O, Lsb0>() {
+		let bits: &BitSlice<Lsb0, T> =
+			unsafe { &*(bits as *const _ as *const _) };
+		bits.load_le()
+	}
+	else if dvl::match_order::<O, Msb0>() {
+		let bits: &BitSlice<Msb0, T> =
+			unsafe { &*(bits as *const _ as *const _) };
+		//  `load_be` puts index `0` in the most significant live bit.
+		bits.load_be::<M>().reverse_bits()
+			>> (M::BITS as usize - bits.len()) as u8
+	}
+	else {
+		bits.iter()
+			.by_val()
+			.enumerate()
+			.fold(M::ZERO, |word, (idx, bit)| {
+				word | resize::<u8, M>(bit as u8) << idx as u8
+			})
+	}
+}
+
+/// Stores the low bits of `value` into a region, with bit `k` of `value` in
+/// index `k` of the region.
+///
+/// This is the inverse of [`load_indexed`].
+///
+/// # Panics
+///
+/// This panics if `bits` is empty, or wider than [`M::BITS`].
+///
+/// [`M::BITS`]: funty::IsNumber::BITS
+/// [`load_indexed`]: self::load_indexed
+#[inline]
+#[cfg(feature = "alloc")]
+pub(crate) fn store_indexed<O, T, M>(bits: &mut BitSlice<O, T>, value: M)
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	check::<M>("store", bits.len());
+	if dvl::match_order::<O, Lsb0>() {
+		let bits: &mut BitSlice<Lsb0, T> =
+			unsafe { &mut *(bits as *mut _ as *mut _) };
+		bits.store_le(value);
+	}
+	else if dvl::match_order::<O, Msb0>() {
+		let bits: &mut BitSlice<Msb0, T> =
+			unsafe { &mut *(bits as *mut _ as *mut _) };
+		let shamt = (M::BITS as usize - bits.len()) as u8;
+		bits.store_be(value.reverse_bits() >> shamt);
+	}
+	else {
+		for idx in 0 .. bits.len() {
+			let bit = resize::<M, u8>(value >> idx as u8) & 1 != 0;
+			unsafe {
+				bits.set_unchecked(idx, bit);
+			}
+		}
+	}
+}
+
 /** Reads a value out of a section of a memory element.
 
 This function is used to extract a portion of an `M` value from a portion of a
@@ -1643,8 +1732,24 @@ compile_fail!(concat!(
 	env!(CARGO_PKG_REPOSITORY)
 ));
 
+mod arith;
+mod fields;
 #[cfg(feature = "std")]
 mod io;
+mod signed;
+mod stream;
+mod words;
+
+#[cfg(feature = "alloc")]
+pub use self::stream::BitWriter;
+pub use self::{
+	fields::Fields,
+	stream::{
+		BitReader,
+		Endian,
+	},
+	words::Words,
+};
 
 #[cfg(test)]
 mod tests;
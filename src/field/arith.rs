@@ -0,0 +1,752 @@
+/*! Unsigned integer arithmetic on bit-slices of any width.
+
+These methods read a whole [`BitSlice`] as one unsigned number, in the same way
+that [`BitField`] reads a slice no wider than a register: each memory element
+contributes the value of its live bits, and the [`Endian`] argument chooses
+whether the lowest-addressed element is the least ([`Endian::Little`], as in
+[`BitField::load_le`]) or the most ([`Endian::Big`], as in
+[`BitField::load_be`]) significant. A [`BitArray`] of 256 bits can therefore be
+used as a `u256` register without a separate bignum type.
+
+As with [`BitField`], the significance of a bit depends on the element
+boundaries of the bit-slice, not only on its index. When the bit ordering runs
+against the element ordering ([`Msb0`] with [`Endian::Little`], or [`Lsb0`]
+with [`Endian::Big`]), extending a bit-slice into more of its last element
+changes the value of the bits it already had.
+
+The arithmetic works one memory element (a *limb*) at a time, with carries
+propagated between limbs in order of significance. The right-hand operand may
+have any ordering, storage type, and width. It is read with the same element
+ordering as the left-hand operand, and is not required to be aligned with it.
+
+[`BitArray`]: crate::array::BitArray
+[`BitField`]: crate::field::BitField
+[`BitField::load_be`]: crate::field::BitField::load_be
+[`BitField::load_le`]: crate::field::BitField::load_le
+[`BitSlice`]: crate::slice::BitSlice
+[`Endian`]: crate::field::Endian
+[`Endian::Big`]: crate::field::Endian::Big
+[`Endian::Little`]: crate::field::Endian::Little
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+!*/
+
+use core::{
+	cmp::Ordering,
+	ops::Range,
+};
+
+use funty::IsNumber;
+
+use crate::{
+	array::BitArray,
+	domain::BitDomain,
+	field::{
+		BitField,
+		Endian,
+	},
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	view::BitView,
+};
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Adds `rhs` to `self`, wrapping at the width of `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The addend. Bits of `rhs` beyond the width of `self` are
+	///   discarded.
+	/// - `endian`: The element ordering of both numbers.
+	///
+	/// # Returns
+	///
+	/// `true` if the true sum does not fit in `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let mut data = [0xFFu8, 0x7F];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// assert!(!bits.overflowing_add_assign(1u8.view_bits::<Msb0>(), Endian::Little));
+	/// assert_eq!(data, [0x00, 0x80]);
+	///
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// assert!(bits.overflowing_add_assign(0x8000u16.view_bits::<Lsb0>(), Endian::Little));
+	/// assert_eq!(data, [0, 0]);
+	/// ```
+	pub fn overflowing_add_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let limbs = Limbs::new(self, endian);
+		let mut rhs = Digits::new(rhs, endian);
+		let mut carry = 0u128;
+		for limb in 0 .. limbs.count {
+			let range = limbs.range(limb);
+			let width = range.len();
+			let sum = self[range.clone()].load_le::<u64>() as u128
+				+ rhs.take(width) as u128
+				+ carry;
+			self[range].store_le(sum as u64 & mask(width));
+			carry = sum >> width;
+		}
+		carry != 0 || rhs.rest_nonzero()
+	}
+
+	/// Adds `rhs` to `self`, wrapping at the width of `self`.
+	///
+	/// This is [`.overflowing_add_assign()`], without the overflow report.
+	///
+	/// [`.overflowing_add_assign()`]: Self::overflowing_add_assign
+	#[inline]
+	pub fn wrapping_add_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		self.overflowing_add_assign(rhs, endian);
+	}
+
+	/// Subtracts `rhs` from `self`, wrapping at the width of `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The subtrahend. Bits of `rhs` beyond the width of `self` are
+	///   discarded.
+	/// - `endian`: The element ordering of both numbers.
+	///
+	/// # Returns
+	///
+	/// `true` if `rhs` is greater than `self`, so that the true difference is
+	/// negative.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let mut data = [0x01u8, 0x00];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// assert!(!bits.overflowing_sub_assign(bits![1], Endian::Big));
+	/// assert_eq!(data, [0x00, 0xFF]);
+	/// ```
+	pub fn overflowing_sub_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let limbs = Limbs::new(self, endian);
+		let mut rhs = Digits::new(rhs, endian);
+		let mut borrow = 0u128;
+		for limb in 0 .. limbs.count {
+			let range = limbs.range(limb);
+			let width = range.len();
+			//  Lend the next limb’s unit in advance; it is repaid below if the
+			//  difference did not need it.
+			let diff = (1u128 << width)
+				+ self[range.clone()].load_le::<u64>() as u128
+				- rhs.take(width) as u128
+				- borrow;
+			self[range].store_le(diff as u64 & mask(width));
+			borrow = 1 - (diff >> width);
+		}
+		borrow != 0 || rhs.rest_nonzero()
+	}
+
+	/// Subtracts `rhs` from `self`, wrapping at the width of `self`.
+	///
+	/// This is [`.overflowing_sub_assign()`], without the overflow report.
+	///
+	/// [`.overflowing_sub_assign()`]: Self::overflowing_sub_assign
+	#[inline]
+	pub fn wrapping_sub_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		self.overflowing_sub_assign(rhs, endian);
+	}
+
+	/// Multiplies `self` by `rhs`, wrapping at the width of `self`.
+	///
+	/// The product is accumulated in place, from the most significant limb of
+	/// `self` downwards, so this does not need any scratch memory. It takes
+	/// time proportional to the square of the number of limbs.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The multiplier.
+	/// - `endian`: The element ordering of both numbers.
+	///
+	/// # Returns
+	///
+	/// `true` if the true product does not fit in `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let mut data = [0xFFFFu16, 0, 0];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// let rhs = 0x1_0001_0001u64;
+	/// assert!(!bits.overflowing_mul_assign(rhs.view_bits::<Lsb0>(), Endian::Little));
+	/// assert_eq!(data, [0xFFFF; 3]);
+	/// ```
+	pub fn overflowing_mul_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let limbs = Limbs::new(self, endian);
+		let mut overflow = false;
+		//  Limbs above `limb` already hold the product of their original values
+		//  with `rhs`. Replacing `limb` with its own partial product, and adding
+		//  the carries upwards, extends that product by one limb.
+		for limb in (0 .. limbs.count).rev() {
+			let range = limbs.range(limb);
+			let factor = self[range.clone()].load_le::<u64>() as u128;
+			self[range].store_le(0u64);
+			if factor == 0 {
+				continue;
+			}
+
+			let mut digits = Digits::new(rhs, endian);
+			let (mut product, mut carry) = (0u128, 0u128);
+			for limb in limb .. limbs.count {
+				let range = limbs.range(limb);
+				let width = range.len();
+				//  The carried part of `product` stays below twice `factor`, so
+				//  this cannot exceed `u128`.
+				product += factor * digits.take(width) as u128;
+				let sum = self[range.clone()].load_le::<u64>() as u128
+					+ (product & mask(width) as u128)
+					+ carry;
+				self[range].store_le(sum as u64 & mask(width));
+				product >>= width;
+				carry = sum >> width;
+			}
+			overflow |= product != 0 || carry != 0 || digits.rest_nonzero();
+		}
+		overflow
+	}
+
+	/// Multiplies `self` by `rhs`, wrapping at the width of `self`.
+	///
+	/// This is [`.overflowing_mul_assign()`], without the overflow report.
+	///
+	/// [`.overflowing_mul_assign()`]: Self::overflowing_mul_assign
+	#[inline]
+	pub fn wrapping_mul_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		self.overflowing_mul_assign(rhs, endian);
+	}
+
+	/// Compares the numeric values of `self` and `rhs`.
+	///
+	/// Unlike the [`Ord`] implementation, which compares bit-slices
+	/// lexicographically, this compares the numbers that they encode. Numbers
+	/// of different widths are compared as if the narrower were zero-extended.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = [0x00u8, 0x01];
+	/// let b = [0xFFu8];
+	/// let (a, b) = (a.view_bits::<Lsb0>(), b.view_bits::<Lsb0>());
+	/// assert_eq!(a.cmp_numeric(b, Endian::Little), Ordering::Greater);
+	/// assert_eq!(a.cmp_numeric(b, Endian::Big), Ordering::Less);
+	/// ```
+	///
+	/// [`Ord`]: core::cmp::Ord
+	pub fn cmp_numeric<O2, T2>(
+		&self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Ordering
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let mut lhs = Digits::new(self, endian);
+		let mut rhs = Digits::new(rhs, endian);
+		let mut out = Ordering::Equal;
+		//  Later digits are more significant, and so override earlier ones.
+		while !(lhs.is_empty() && rhs.is_empty()) {
+			match lhs.take(64).cmp(&rhs.take(64)) {
+				Ordering::Equal => {},
+				ord => out = ord,
+			}
+		}
+		out
+	}
+
+	/// Reads the numeric value of `self`, if it fits in a `u64`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let data = [0x12u8, 0x34, 0x00];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits.to_u64(Endian::Little), Some(0x3412));
+	/// assert_eq!(bits.to_u64(Endian::Big), Some(0x12_3400));
+	/// assert_eq!(bits![1; 65].to_u64(Endian::Little), None);
+	/// ```
+	pub fn to_u64(&self, endian: Endian) -> Option<u64> {
+		let mut digits = Digits::new(self, endian);
+		let out = digits.take(64);
+		if digits.rest_nonzero() {
+			None
+		}
+		else {
+			Some(out)
+		}
+	}
+}
+
+impl<O, V> BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	BitSlice<O, V::Store>: BitField,
+{
+	/// Constructs an array holding a number, truncated to the array’s width.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let arr = BitArray::<Msb0, [u8; 3]>::from_u64(0x12_3456, Endian::Big);
+	/// assert_eq!(arr.value(), [0x12, 0x34, 0x56]);
+	/// assert_eq!(arr.to_u64(Endian::Big), Some(0x12_3456));
+	/// ```
+	pub fn from_u64(value: u64, endian: Endian) -> Self {
+		let mut out = Self::zeroed();
+		let limbs = Limbs::new(&out, endian);
+		let mut value = value as u128;
+		for limb in 0 .. limbs.count {
+			let range = limbs.range(limb);
+			let width = range.len();
+			out[range].store_le(value as u64 & mask(width));
+			value >>= width;
+		}
+		out
+	}
+
+	/// Adds `rhs` to `self`, wrapping at the width of `self`.
+	///
+	/// See [`BitSlice::overflowing_add_assign`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// type U256 = BitArr!(for 256, in Lsb0, u64);
+	/// let max = !U256::zeroed();
+	/// let one = U256::from_u64(1, Endian::Little);
+	/// assert!(max.wrapping_add(&one, Endian::Little).not_any());
+	/// ```
+	///
+	/// [`BitSlice::overflowing_add_assign`]: crate::slice::BitSlice::overflowing_add_assign
+	#[inline]
+	pub fn wrapping_add<O2, T2>(
+		self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		self.overflowing_add(rhs, endian).0
+	}
+
+	/// Adds `rhs` to `self`, returning `None` if the sum does not fit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// type U256 = BitArr!(for 256, in Lsb0, u64);
+	/// let one = U256::from_u64(1, Endian::Little);
+	/// let two = one.checked_add(&one, Endian::Little).unwrap();
+	/// assert_eq!(two.to_u64(Endian::Little), Some(2));
+	/// assert!((!U256::zeroed()).checked_add(&one, Endian::Little).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_add<O2, T2>(
+		self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Option<Self>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		checked(self.overflowing_add(rhs, endian))
+	}
+
+	/// Adds `rhs` to `self`, returning the wrapped sum and whether it
+	/// overflowed.
+	///
+	/// See [`BitSlice::overflowing_add_assign`].
+	///
+	/// [`BitSlice::overflowing_add_assign`]: crate::slice::BitSlice::overflowing_add_assign
+	#[inline]
+	pub fn overflowing_add<O2, T2>(
+		mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> (Self, bool)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let overflow = self.overflowing_add_assign(rhs, endian);
+		(self, overflow)
+	}
+
+	/// Subtracts `rhs` from `self`, wrapping at the width of `self`.
+	///
+	/// See [`BitSlice::overflowing_sub_assign`].
+	///
+	/// [`BitSlice::overflowing_sub_assign`]: crate::slice::BitSlice::overflowing_sub_assign
+	#[inline]
+	pub fn wrapping_sub<O2, T2>(
+		self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		self.overflowing_sub(rhs, endian).0
+	}
+
+	/// Subtracts `rhs` from `self`, returning `None` if `rhs` is greater.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// type U512 = BitArr!(for 512, in Msb0, u32);
+	/// let ten = U512::from_u64(10, Endian::Big);
+	/// let three = U512::from_u64(3, Endian::Big);
+	/// let seven = ten.checked_sub(&three, Endian::Big).unwrap();
+	/// assert_eq!(seven.to_u64(Endian::Big), Some(7));
+	/// assert!(three.checked_sub(&ten, Endian::Big).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_sub<O2, T2>(
+		self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Option<Self>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		checked(self.overflowing_sub(rhs, endian))
+	}
+
+	/// Subtracts `rhs` from `self`, returning the wrapped difference and
+	/// whether it overflowed.
+	///
+	/// See [`BitSlice::overflowing_sub_assign`].
+	///
+	/// [`BitSlice::overflowing_sub_assign`]: crate::slice::BitSlice::overflowing_sub_assign
+	#[inline]
+	pub fn overflowing_sub<O2, T2>(
+		mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> (Self, bool)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let overflow = self.overflowing_sub_assign(rhs, endian);
+		(self, overflow)
+	}
+
+	/// Multiplies `self` by `rhs`, wrapping at the width of `self`.
+	///
+	/// See [`BitSlice::overflowing_mul_assign`].
+	///
+	/// [`BitSlice::overflowing_mul_assign`]: crate::slice::BitSlice::overflowing_mul_assign
+	#[inline]
+	pub fn wrapping_mul<O2, T2>(
+		self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		self.overflowing_mul(rhs, endian).0
+	}
+
+	/// Multiplies `self` by `rhs`, returning `None` if the product does not
+	/// fit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// type U256 = BitArr!(for 256, in Lsb0, u64);
+	/// let big = U256::from_u64(u64::MAX, Endian::Little);
+	/// let square = big.checked_mul(&big, Endian::Little).unwrap();
+	/// assert_eq!(square[.. 128].load_le::<u128>(), (u64::MAX as u128).pow(2));
+	/// assert!(square.checked_mul(&square, Endian::Little).unwrap().checked_mul(&big, Endian::Little).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_mul<O2, T2>(
+		self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> Option<Self>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		checked(self.overflowing_mul(rhs, endian))
+	}
+
+	/// Multiplies `self` by `rhs`, returning the wrapped product and whether it
+	/// overflowed.
+	///
+	/// See [`BitSlice::overflowing_mul_assign`].
+	///
+	/// [`BitSlice::overflowing_mul_assign`]: crate::slice::BitSlice::overflowing_mul_assign
+	#[inline]
+	pub fn overflowing_mul<O2, T2>(
+		mut self,
+		rhs: &BitSlice<O2, T2>,
+		endian: Endian,
+	) -> (Self, bool)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O2, T2>: BitField,
+	{
+		let overflow = self.overflowing_mul_assign(rhs, endian);
+		(self, overflow)
+	}
+}
+
+/// The limb boundaries of a bit-slice.
+///
+/// Each limb is the part of the bit-slice that lies in one memory element.
+/// Limbs are numbered from least to most significant.
+#[derive(Clone, Copy, Debug)]
+struct Limbs {
+	/// The length of the bit-slice.
+	len: usize,
+	/// The length of the lowest-addressed limb.
+	first: usize,
+	/// The width of a memory element.
+	width: usize,
+	/// The number of limbs.
+	count: usize,
+	/// Whether the lowest-addressed limb is the least significant.
+	endian: Endian,
+}
+
+impl Limbs {
+	/// Finds the limbs of a bit-slice.
+	fn new<O, T>(bits: &BitSlice<O, T>, endian: Endian) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = bits.len();
+		let width = T::Mem::BITS as usize;
+		let first = match bits.bit_domain() {
+			BitDomain::Enclave { .. } => len,
+			BitDomain::Region { head, .. } if !head.is_empty() => head.len(),
+			BitDomain::Region { .. } => width.min(len),
+		};
+		let count = match len {
+			0 => 0,
+			n if n <= first => 1,
+			n => 1 + (n - first + width - 1) / width,
+		};
+		Self {
+			len,
+			first,
+			width,
+			count,
+			endian,
+		}
+	}
+
+	/// Finds the indices of a limb in the bit-slice, by its significance.
+	fn range(&self, limb: usize) -> Range<usize> {
+		let limb = match self.endian {
+			Endian::Little => limb,
+			Endian::Big => self.count - 1 - limb,
+		};
+		if limb == 0 {
+			return 0 .. self.first;
+		}
+		let start = self.first + (limb - 1) * self.width;
+		start .. self.len.min(start + self.width)
+	}
+}
+
+/// Reads a bit-slice’s number in digits of arbitrary width, from least to most
+/// significant.
+///
+/// Digits past the end of the number are `0`.
+struct Digits<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The bit-slice being read.
+	bits: &'a BitSlice<O, T>,
+	/// Its limb boundaries.
+	limbs: Limbs,
+	/// The next limb to read.
+	next: usize,
+	/// Bits read from limbs, but not yet taken as digits.
+	buffer: u128,
+	/// The number of bits in `buffer`.
+	buffered: usize,
+}
+
+impl<'a, O, T> Digits<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Begins reading a bit-slice at its least significant limb.
+	fn new(bits: &'a BitSlice<O, T>, endian: Endian) -> Self {
+		Self {
+			bits,
+			limbs: Limbs::new(bits, endian),
+			next: 0,
+			buffer: 0,
+			buffered: 0,
+		}
+	}
+
+	/// Tests if every bit of the number has been taken.
+	fn is_empty(&self) -> bool {
+		self.buffered == 0 && self.next == self.limbs.count
+	}
+
+	/// Takes the next `width` bits of the number, where `width` is at most 64.
+	fn take(&mut self, width: usize) -> u64 {
+		while self.buffered < width && self.next < self.limbs.count {
+			let range = self.limbs.range(self.next);
+			let len = range.len();
+			let limb = self.bits[range].load_le::<u64>() as u128;
+			self.buffer |= limb << self.buffered;
+			self.buffered += len;
+			self.next += 1;
+		}
+		let out = self.buffer as u64 & mask(width);
+		self.buffer >>= width;
+		self.buffered = self.buffered.saturating_sub(width);
+		out
+	}
+
+	/// Tests if any bit not yet taken is `1`.
+	fn rest_nonzero(&mut self) -> bool {
+		while !self.is_empty() {
+			if self.take(64) != 0 {
+				return true;
+			}
+		}
+		false
+	}
+}
+
+/// Produces a mask of the low `width` bits of a `u64`, where `width` is at most
+/// 64.
+#[inline]
+fn mask(width: usize) -> u64 {
+	if width >= 64 { !0 } else { (1 << width) - 1 }
+}
+
+/// Converts an overflowing result into a checked one.
+#[inline]
+fn checked<T>((value, overflow): (T, bool)) -> Option<T> {
+	if overflow { None } else { Some(value) }
+}
@@ -0,0 +1,500 @@
+/*! Arrays of fixed-width integer fields.
+
+Sample buffers and packed tables frequently hold a run of integers that are all
+the same number of bits wide, and that width is often not a multiple of eight.
+This module reads such a run out of a [`BitSlice`] as an iterator of integers,
+and writes one from an iterator into a [`BitSlice`] or onto the end of a
+[`BitVec`].
+
+Each field is transferred with the [`BitField`] method pair that the chosen
+[`Endian`] selects. When the field width divides the width of the memory
+element, and the bit-slice begins on a field boundary within its first element,
+no field crosses an element boundary. The two element orderings then agree, and
+the transfers shift fields directly in and out of whole elements instead of
+walking a domain for each field.
+
+[`BitField`]: crate::field::BitField
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`Endian`]: crate::field::Endian
+!*/
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+	marker::PhantomData,
+};
+
+use funty::IsNumber;
+
+use super::{
+	check,
+	resize,
+	words::mask,
+	Endian,
+};
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	devel as dvl,
+	field::BitField,
+	mem::BitMemory,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Iterates over the bit-slice as a sequence of `width`-bit integers.
+	///
+	/// This behaves like `.chunks_exact(width)`, with each chunk loaded by the
+	/// [`BitField`] method that `endian` selects. Bits at the end of the
+	/// bit-slice that do not fill a field are not yielded, and are available
+	/// from [`Fields::remainder`].
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type into which each field is loaded.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The number of bits in each field.
+	/// - `endian`: The element ordering of fields that span more than one
+	///   memory element.
+	///
+	/// # Returns
+	///
+	/// A double-ended iterator over the fields of `self`. Its [`nth`] and
+	/// [`nth_back`] methods skip fields without loading them.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than `M`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let bits = data.view_bits::<Msb0>();
+	/// let mut samples = bits.iter_fields::<u16>(12, Endian::Big);
+	/// assert_eq!(samples.len(), 2);
+	/// assert_eq!(samples.next(), Some(0x123));
+	/// assert_eq!(samples.next_back(), Some(0x456));
+	/// assert!(samples.next().is_none());
+	///
+	/// let nibbles = bits.iter_fields::<u8>(4, Endian::Big);
+	/// assert_eq!(nibbles.rev().nth(1), Some(5));
+	/// ```
+	///
+	/// [`BitField`]: crate::field::BitField
+	/// [`Fields::remainder`]: crate::field::Fields::remainder
+	/// [`nth`]: core::iter::Iterator::nth
+	/// [`nth_back`]: core::iter::DoubleEndedIterator::nth_back
+	#[inline]
+	pub fn iter_fields<M>(
+		&self,
+		width: usize,
+		endian: Endian,
+	) -> Fields<O, T, M>
+	where
+		M: BitMemory,
+	{
+		check::<M>("load", width);
+		let count = self.len() / width;
+		let (bits, rem) = unsafe { self.split_at_unchecked(count * width) };
+		Fields {
+			bits,
+			rem,
+			width,
+			endian,
+			aligned: is_aligned(self, width),
+			front: 0,
+			back: count,
+			_mem: PhantomData,
+		}
+	}
+
+	/// Writes a sequence of integers into the bit-slice as `width`-bit fields.
+	///
+	/// This is the inverse of [`.iter_fields()`]. Each value is truncated to
+	/// its `width` least significant bits, and stored into the next field by
+	/// the [`BitField`] method that `endian` selects.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type of each value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The number of bits in each field.
+	/// - `endian`: The element ordering of fields that span more than one
+	///   memory element.
+	/// - `values`: The values to store. Writing stops when the values run out,
+	///   or when the bit-slice has no room for another whole field.
+	///
+	/// # Returns
+	///
+	/// The number of fields written.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than `M`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let mut data = [0u8; 3];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// let samples = [0x123u16, 0x456, 0x789].iter().copied();
+	/// assert_eq!(bits.store_fields(12, Endian::Big, samples), 2);
+	/// assert_eq!(data, [0x12, 0x34, 0x56]);
+	/// ```
+	///
+	/// [`BitField`]: crate::field::BitField
+	/// [`.iter_fields()`]: Self::iter_fields
+	#[inline]
+	pub fn store_fields<M, I>(
+		&mut self,
+		width: usize,
+		endian: Endian,
+		values: I,
+	) -> usize
+	where
+		M: BitMemory,
+		I: IntoIterator<Item = M>,
+	{
+		check::<M>("store", width);
+		let count = self.len() / width;
+		let mut values = values.into_iter();
+		if !is_aligned(self, width) {
+			for idx in 0 .. count {
+				let value = match values.next() {
+					Some(value) => value,
+					None => return idx,
+				};
+				let start = idx * width;
+				let dest =
+					unsafe { self.get_unchecked_mut(start .. start + width) };
+				endian.store(dest, value);
+			}
+			return count;
+		}
+
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		let lsb0 = dvl::match_order::<O, Lsb0>();
+		let per_elem = bits / width;
+		let head = self.as_bitspan().head().value() as usize;
+		let mut idx = 0;
+		while idx < count {
+			let start = idx * width;
+			//  Fields that fill an element are gathered and written together.
+			//  The element lies wholly inside `self`, so no other handle can
+			//  observe it, and it is overwritten without a mask.
+			if (head + start) % bits == 0 && count - idx >= per_elem {
+				let mut elem = 0u64;
+				let mut taken = 0;
+				for (slot, value) in values.by_ref().take(per_elem).enumerate() {
+					let shamt = if lsb0 {
+						slot * width
+					}
+					else {
+						bits - (slot + 1) * width
+					};
+					elem |= (resize::<M, u64>(value) & mask(width)) << shamt;
+					taken += 1;
+				}
+				let len = taken * width;
+				let dest =
+					unsafe { self.get_unchecked_mut(start .. start + len) };
+				if taken == per_elem {
+					let addr = dest.as_mut_bitspan().address().to_mut();
+					unsafe { &mut *addr }.store_value(resize(elem));
+				}
+				else {
+					if taken != 0 {
+						//  Under `Msb0`, the gathered fields are at the most
+						//  significant edge of `elem`.
+						let shamt = if lsb0 { 0 } else { bits - len };
+						dest.store_le(elem >> shamt);
+					}
+					return idx + taken;
+				}
+				idx += per_elem;
+			}
+			else {
+				let value = match values.next() {
+					Some(value) => value,
+					None => return idx,
+				};
+				unsafe { self.get_unchecked_mut(start .. start + width) }
+					.store_le(value);
+				idx += 1;
+			}
+		}
+		count
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Appends a sequence of integers to the bit-vector as `width`-bit fields.
+	///
+	/// Each value is truncated to its `width` least significant bits. The
+	/// bit-vector grows by `width` bits for each value, and the fields are
+	/// written with [`.store_fields()`].
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type of each value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The number of bits in each field.
+	/// - `endian`: The element ordering of fields that span more than one
+	///   memory element.
+	/// - `values`: The values to append.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than `M`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::Endian;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1; 4];
+	/// bv.extend_fields(5, Endian::Big, vec![0b10110u8, 0b00111]);
+	/// assert_eq!(bv.len(), 14);
+	/// assert_eq!(bv.as_raw_slice(), [0b1111_1011, 0b0_00111_00]);
+	/// ```
+	///
+	/// [`.store_fields()`]: crate::slice::BitSlice::store_fields
+	#[inline]
+	pub fn extend_fields<M, I>(
+		&mut self,
+		width: usize,
+		endian: Endian,
+		values: I,
+	) where
+		M: BitMemory,
+		I: IntoIterator<Item = M>,
+	{
+		check::<M>("store", width);
+		let mut values = values.into_iter();
+		loop {
+			//  Grow by as many fields as the iterator promises, or by one.
+			let batch = cmp::max(values.size_hint().0, 1);
+			let start = self.len();
+			self.resize(start + batch * width, false);
+			let written = self[start ..].store_fields(
+				width,
+				endian,
+				values.by_ref().take(batch),
+			);
+			if written < batch {
+				self.truncate(start + written * width);
+				return;
+			}
+		}
+	}
+}
+
+/** An iterator over a [`BitSlice`] as a sequence of fixed-width integers.
+
+This is produced by [`BitSlice::iter_fields`].
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::iter_fields`]: crate::slice::BitSlice::iter_fields
+**/
+#[derive(Clone, Debug)]
+pub struct Fields<'a, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	/// The bits holding whole fields.
+	bits: &'a BitSlice<O, T>,
+	/// The bits after the last whole field.
+	rem: &'a BitSlice<O, T>,
+	/// The number of bits in each field.
+	width: usize,
+	/// The element ordering of fields that span elements.
+	endian: Endian,
+	/// Marks that no field spans elements.
+	aligned: bool,
+	/// The index of the next field to yield from the front.
+	front: usize,
+	/// The index after the next field to yield from the back.
+	back: usize,
+	/// The field type.
+	_mem: PhantomData<M>,
+}
+
+impl<'a, O, T, M> Fields<'a, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	/// Gets the bits after the last whole field.
+	///
+	/// # Returns
+	///
+	/// The bits at the end of the source bit-slice that are too few to form a
+	/// field. They are never yielded.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn remainder(&self) -> &'a BitSlice<O, T> {
+		self.rem
+	}
+
+	/// Loads the field at `idx`.
+	///
+	/// `idx` must be less than the number of whole fields.
+	fn load(&self, idx: usize) -> M {
+		let start = idx * self.width;
+		if !self.aligned {
+			let field =
+				unsafe { self.bits.get_unchecked(start .. start + self.width) };
+			return self.endian.load(field);
+		}
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		let (addr, head, _) = self.bits.as_bitspan().raw_parts();
+		let pos = head.value() as usize + start;
+		let elem = unsafe { &*addr.to_const().add(pos / bits) };
+		let offset = pos % bits;
+		let shamt = if dvl::match_order::<O, Lsb0>() {
+			offset
+		}
+		else {
+			bits - offset - self.width
+		};
+		let raw = resize::<T::Mem, u64>(elem.load_value());
+		resize::<u64, M>(raw >> shamt & mask(self.width))
+	}
+}
+
+impl<O, T, M> Iterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	type Item = M;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		let out = self.load(self.front);
+		self.front += 1;
+		Some(out)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.front += cmp::min(n, self.len());
+		self.next()
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T, M> DoubleEndedIterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.load(self.back))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.back -= cmp::min(n, self.len());
+		self.next_back()
+	}
+}
+
+impl<O, T, M> ExactSizeIterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+impl<O, T, M> FusedIterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+}
+
+/// Tests if no `width`-bit field of `bits` crosses an element boundary.
+#[inline]
+fn is_aligned<O, T>(bits: &BitSlice<O, T>, width: usize) -> bool
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let elem = <T::Mem as IsNumber>::BITS as usize;
+	let head = bits.as_bitspan().head().value() as usize;
+	elem % width == 0 && head % width == 0
+}
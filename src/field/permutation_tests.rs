@@ -37,6 +37,16 @@ fn check_resize() {
 			0x0123_4567_89AB_CDEFu64
 		);
 	}
+
+	let wide = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+	assert_eq!(resize::<u128, u8>(wide), 0x10u8);
+	assert_eq!(resize::<u128, u32>(wide), 0x7654_3210u32);
+	assert_eq!(resize::<u128, u128>(wide), wide);
+	assert_eq!(resize::<u32, u128>(0x1234_5678u32), 0x1234_5678u128);
+	assert_eq!(
+		resize::<u64, u128>(0x0123_4567_89AB_CDEFu64),
+		0x0123_4567_89AB_CDEFu128
+	);
 }
 
 #[test]
@@ -118,3 +128,54 @@ fn m16() {
 		}
 	}
 }
+
+/// `u128` is not a register type, but it is a valid `BitField` transfer type.
+/// This checks every head index and every width that requires more than one
+/// `u64`, for each ordering and storage type.
+#[test]
+#[cfg(not(miri))]
+fn wide_u128() {
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let mut data = [<$t>::ZERO; 32];
+			let bits = data.view_bits_mut::<$o>();
+			let patterns = [
+				0u128,
+				!0,
+				0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+				0xA5A5_A5A5_A5A5_A5A5_A5A5_A5A5_A5A5_A5A5,
+				1 << 127 | 1,
+			];
+
+			for head in 0 .. <$t>::BITS as usize {
+				for len in 65 ..= 128 {
+					let field = &mut bits[head ..][.. len];
+					let mask = !0u128 >> (128 - len);
+					for &n in &patterns {
+						field.store_le::<u128>(n);
+						assert_eq!(field.load_le::<u128>(), n & mask);
+						field.store_be::<u128>(n);
+						assert_eq!(field.load_be::<u128>(), n & mask);
+					}
+				}
+			}
+		)+ };
+	}
+
+	check! {
+		Lsb0, u8;
+		Msb0, u8;
+		Lsb0, u16;
+		Msb0, u16;
+		Lsb0, u32;
+		Msb0, u32;
+		Lsb0, usize;
+		Msb0, usize;
+	}
+
+	#[cfg(target_pointer_width = "64")]
+	check! {
+		Lsb0, u64;
+		Msb0, u64;
+	}
+}
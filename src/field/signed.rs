@@ -0,0 +1,279 @@
+/*! Two’s-complement signed access to [`BitSlice`] regions.
+
+[`BitField`] transfers unsigned integers, and zero-extends a region that is
+narrower than its destination. Signed loads instead treat the most significant
+bit of the region as its sign, and extend it through the rest of the integer;
+signed stores check that the value fits in the region rather than discarding its
+high bits.
+
+These are inherent methods of [`BitSlice`] rather than members of [`BitField`],
+because they need the width of the region, which the trait does not expose.
+[`BitArray`], [`BitBox`], and [`BitVec`] reach them by dereference.
+
+[`BitArray`]: crate::array::BitArray
+[`BitBox`]: crate::boxed::BitBox
+[`BitField`]: crate::field::BitField
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+!*/
+
+use funty::IsSigned;
+
+use super::check;
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads the bits in the `self` region as a two’s-complement signed value.
+	///
+	/// The most significant bit of the region is its sign bit, and is extended
+	/// through the rest of the returned integer.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// This calls [`.load_signed_le()`] on little-endian byte-ordered CPUs,
+	/// and [`.load_signed_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A read reference to some bits in memory. This slice must be
+	///   trimmed to have a width no more than the [`S::BITS`] width of the type
+	///   being loaded.
+	///
+	/// # Returns
+	///
+	/// The bits of `self`, sign-extended from [`self.len()`] bits to an `S`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or wider than a single element `S`.
+	///
+	/// [`S::BITS`]: funty::IsNumber::BITS
+	/// [`.load_signed_be()`]: Self::load_signed_be
+	/// [`.load_signed_le()`]: Self::load_signed_le
+	/// [`self.len()`]: Self::len
+	#[inline]
+	pub fn load_signed<S>(&self) -> S
+	where S: IsSigned {
+		#[cfg(target_endian = "little")]
+		return self.load_signed_le::<S>();
+
+		#[cfg(target_endian = "big")]
+		return self.load_signed_be::<S>();
+	}
+
+	/// Stores a two’s-complement signed value into the domain of `self`.
+	///
+	/// # Target-Specific Behavior
+	///
+	/// **THIS FUNCTION CHANGES BEHAVIOR FOR DIFFERENT TARGETS.**
+	///
+	/// This calls [`.store_signed_le()`] on little-endian byte-ordered CPUs,
+	/// and [`.store_signed_be()`] on big-endian byte-ordered CPUs.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A write reference to some bits in memory. This slice must
+	///   be trimmed to have a width no more than the [`S::BITS`] width of the
+	///   type being stored.
+	/// - `value`: A value which must be representable in [`self.len()`] bits of
+	///   two’s-complement.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, wider than a single element `S`, or too
+	/// narrow to hold `value`.
+	///
+	/// [`S::BITS`]: funty::IsNumber::BITS
+	/// [`self.len()`]: Self::len
+	/// [`.store_signed_be()`]: Self::store_signed_be
+	/// [`.store_signed_le()`]: Self::store_signed_le
+	#[inline]
+	pub fn store_signed<S>(&mut self, value: S)
+	where S: IsSigned {
+		#[cfg(target_endian = "little")]
+		self.store_signed_le(value);
+
+		#[cfg(target_endian = "big")]
+		self.store_signed_be(value);
+	}
+
+	/// Loads a two’s-complement signed value from `self`, using little-endian
+	/// element `T` ordering.
+	///
+	/// This has the same segment ordering as [`.load_le()`]. The most
+	/// significant bit of the region is its sign bit, and is extended through
+	/// the rest of the returned integer.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or wider than a single element `S`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0b1010_0000u8, 0b0000_0001];
+	/// let bits = data.view_bits::<Lsb0>();
+	///
+	/// assert_eq!(bits[4 .. 8].load_le::<u8>(), 10);
+	/// assert_eq!(bits[4 .. 8].load_signed_le::<i8>(), -6);
+	/// assert_eq!(bits[4 .. 9].load_signed_le::<i16>(), -6);
+	/// assert_eq!(bits[4 .. 10].load_signed_le::<i32>(), 26);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_signed_le<S>(&self) -> S
+	where S: IsSigned {
+		check::<S>("load", self.len());
+		sign_extend(self.load_le::<u128>(), self.len())
+	}
+
+	/// Loads a two’s-complement signed value from `self`, using big-endian
+	/// element `T` ordering.
+	///
+	/// This has the same segment ordering as [`.load_be()`]. The most
+	/// significant bit of the region is its sign bit, and is extended through
+	/// the rest of the returned integer.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or wider than a single element `S`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0b0000_0110u8, 0b1000_0000];
+	/// let bits = data.view_bits::<Msb0>();
+	///
+	/// assert_eq!(bits[5 .. 9].load_be::<u8>(), 0b1101);
+	/// assert_eq!(bits[5 .. 9].load_signed_be::<i8>(), -3);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_signed_be<S>(&self) -> S
+	where S: IsSigned {
+		check::<S>("load", self.len());
+		sign_extend(self.load_be::<u128>(), self.len())
+	}
+
+	/// Stores a two’s-complement signed value into `self`, using little-endian
+	/// element `T` ordering.
+	///
+	/// This has the same segment ordering as [`.store_le()`], but checks that
+	/// `value` fits in the region rather than discarding its high bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, wider than a single element `S`, or too
+	/// narrow to hold `value`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// bits[4 .. 9].store_signed_le(-6i8);
+	/// assert_eq!(data, [0b1010_0000, 0b0000_0001]);
+	/// ```
+	///
+	/// The value must be representable in the region:
+	///
+	/// ```rust,should_panic
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// data.view_bits_mut::<Lsb0>()[.. 4].store_signed_le(8i8);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_signed_le<S>(&mut self, value: S)
+	where S: IsSigned {
+		check::<S>("store", self.len());
+		self.store_le(sign_truncate(value, self.len()));
+	}
+
+	/// Stores a two’s-complement signed value into `self`, using big-endian
+	/// element `T` ordering.
+	///
+	/// This has the same segment ordering as [`.store_be()`], but checks that
+	/// `value` fits in the region rather than discarding its high bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, wider than a single element `S`, or too
+	/// narrow to hold `value`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	///
+	/// bits[5 .. 9].store_signed_be(-3i8);
+	/// assert_eq!(data, [0b0000_0110, 0b1000_0000]);
+	/// ```
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_signed_be<S>(&mut self, value: S)
+	where S: IsSigned {
+		check::<S>("store", self.len());
+		self.store_be(sign_truncate(value, self.len()));
+	}
+}
+
+/// Sign-extends the low `len` bits of a loaded value.
+///
+/// The caller must have checked that `len` is in `1 ..= S::BITS`.
+fn sign_extend<S>(raw: u128, len: usize) -> S
+where S: IsSigned {
+	let shamt = 128 - len as u32;
+	match S::try_from(((raw << shamt) as i128) >> shamt) {
+		Ok(value) => value,
+		Err(_) => unreachable!("A {}-bit value always fits in {}", len, S::BITS),
+	}
+}
+
+/// Reduces a signed value to the `len` bits that represent it.
+///
+/// The caller must have checked that `len` is in `1 ..= S::BITS`.
+///
+/// # Panics
+///
+/// This panics if `value` is not representable in `len` bits of
+/// two’s-complement.
+fn sign_truncate<S>(value: S, len: usize) -> u128
+where S: IsSigned {
+	let wide: i128 = match value.try_into() {
+		Ok(wide) => wide,
+		Err(_) => unreachable!("Every signed integer fits in i128"),
+	};
+	let shamt = 128 - len as u32;
+	if (wide << shamt) >> shamt != wide {
+		panic!("Cannot store {} into a {}-bit signed region", value, len);
+	}
+	wide as u128
+}
@@ -0,0 +1,516 @@
+/*! Bit-granular stream cursors.
+
+The [`Read`] and [`Write`] implementations in this module’s sibling are
+strictly byte-based. Bit-packed codecs frequently need to consume or produce
+fields whose widths are not multiples of eight, and whose positions are not
+aligned to byte boundaries. This module provides a reading cursor over a
+borrowed [`BitSlice`], and a writing cursor that appends to an owned [`BitVec`].
+
+Both cursors transfer fields through the [`BitField`] trait, and so they are
+only available for [`BitSlice`] types that implement it. The [`Endian`] chosen
+when the cursor is created selects between [`BitField::load_le`] and
+[`BitField::load_be`] (or the matching store methods) for every multi-bit
+transfer.
+
+[`BitField`]: crate::field::BitField
+[`BitField::load_be`]: crate::field::BitField::load_be
+[`BitField::load_le`]: crate::field::BitField::load_le
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`Endian`]: crate::field::Endian
+[`Read`]: std::io::Read
+[`Write`]: std::io::Write
+!*/
+
+use funty::{
+	IsInteger,
+	IsSigned,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/** Selects the element-ordering behavior of a multi-bit field transfer.
+
+This chooses which [`BitField`] method pair a stream cursor uses to move values
+in and out of memory. See the [`field`] module documentation for a detailed
+explanation of the two behaviors.
+
+[`BitField`]: crate::field::BitField
+[`field`]: crate::field
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endian {
+	/// Transfer fields with [`BitField::load_le`] and [`BitField::store_le`].
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	/// [`BitField::store_le`]: crate::field::BitField::store_le
+	Little,
+	/// Transfer fields with [`BitField::load_be`] and [`BitField::store_be`].
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	/// [`BitField::store_be`]: crate::field::BitField::store_be
+	Big,
+}
+
+impl Endian {
+	/// Loads a value from a bit-slice with the selected element ordering.
+	#[inline]
+	pub(crate) fn load<O, T, M>(self, bits: &BitSlice<O, T>) -> M
+	where
+		O: BitOrder,
+		T: BitStore,
+		BitSlice<O, T>: BitField,
+		M: BitMemory,
+	{
+		match self {
+			Self::Little => bits.load_le(),
+			Self::Big => bits.load_be(),
+		}
+	}
+
+	/// Stores a value into a bit-slice with the selected element ordering.
+	#[inline]
+	pub(crate) fn store<O, T, M>(self, bits: &mut BitSlice<O, T>, value: M)
+	where
+		O: BitOrder,
+		T: BitStore,
+		BitSlice<O, T>: BitField,
+		M: BitMemory,
+	{
+		match self {
+			Self::Little => bits.store_le(value),
+			Self::Big => bits.store_be(value),
+		}
+	}
+}
+
+/** A reading cursor over a [`BitSlice`].
+
+The reader consumes bits from the front of the borrowed region. Each read
+either succeeds completely and advances the cursor, or fails (by returning
+`None`) and leaves the cursor in place, so that a caller can inspect the
+[`remaining`] bits and decide how to recover.
+
+# Type Parameters
+
+- `O`: The ordering of the source bit-slice.
+- `T`: The storage type of the source bit-slice.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::field::{BitReader, Endian};
+
+let data = [0b101_10011u8, 0b1111_0000];
+let mut reader = BitReader::new(data.view_bits::<Msb0>(), Endian::Big);
+
+assert_eq!(reader.read_bits::<u8>(3), Some(0b101));
+assert_eq!(reader.read_bit(), Some(true));
+assert_eq!(reader.read_signed::<i8>(4), Some(0b0011));
+assert_eq!(reader.read_signed::<i8>(4), Some(-1));
+assert_eq!(reader.position(), 12);
+assert_eq!(reader.read_bits::<u8>(5), None);
+assert_eq!(reader.remaining(), 4);
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+[`remaining`]: Self::remaining
+**/
+#[derive(Clone, Debug)]
+pub struct BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The full region being read.
+	bits: &'a BitSlice<O, T>,
+	/// The index of the next bit to be read.
+	pos: usize,
+	/// The element ordering of multi-bit reads.
+	endian: Endian,
+}
+
+impl<'a, O, T> BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Creates a reader positioned at the start of a bit-slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The region to read.
+	/// - `endian`: The element ordering used by all multi-bit reads.
+	///
+	/// # Returns
+	///
+	/// A reader whose [`position`] is zero.
+	///
+	/// [`position`]: Self::position
+	#[inline]
+	pub fn new(bits: &'a BitSlice<O, T>, endian: Endian) -> Self {
+		Self {
+			bits,
+			pos: 0,
+			endian,
+		}
+	}
+
+	/// Gets the number of bits consumed since the reader was created.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the number of bits not yet consumed.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Gets the element ordering used by multi-bit reads.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Views the bits not yet consumed.
+	#[inline]
+	pub fn as_bitslice(&self) -> &'a BitSlice<O, T> {
+		unsafe { self.bits.get_unchecked(self.pos ..) }
+	}
+
+	/// Reads a single bit.
+	///
+	/// # Returns
+	///
+	/// The next bit in the stream, or `None` if the stream is exhausted.
+	#[inline]
+	pub fn read_bit(&mut self) -> Option<bool> {
+		let bit = self.bits.get(self.pos).map(|bit| *bit)?;
+		self.pos += 1;
+		Some(bit)
+	}
+
+	/// Reads an unsigned field of `n` bits.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type into which the field is loaded.
+	///
+	/// # Parameters
+	///
+	/// - `n`: The width of the field. This may be zero, in which case the
+	///   reader does not move and the field is zero.
+	///
+	/// # Returns
+	///
+	/// The next `n` bits of the stream, loaded with the reader’s [`Endian`]
+	/// ordering into the least significant bits of an `M`. If fewer than `n`
+	/// bits remain, this returns `None` and does not advance.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is wider than `M`.
+	///
+	/// [`Endian`]: crate::field::Endian
+	#[inline]
+	pub fn read_bits<M>(&mut self, n: usize) -> Option<M>
+	where M: BitMemory {
+		check_width::<M>("read", n);
+		if n == 0 {
+			return Some(M::ZERO);
+		}
+		let field = self.take(n)?;
+		Some(self.endian.load(field))
+	}
+
+	/// Reads a two’s-complement field of `n` bits.
+	///
+	/// The most significant bit of the field is its sign bit, and is extended
+	/// through the rest of the returned integer.
+	///
+	/// # Type Parameters
+	///
+	/// - `S`: The signed integer type into which the field is loaded.
+	///
+	/// # Parameters
+	///
+	/// - `n`: The width of the field. This may be zero, in which case the
+	///   reader does not move and the field is zero.
+	///
+	/// # Returns
+	///
+	/// The next `n` bits of the stream, sign-extended into an `S`. If fewer
+	/// than `n` bits remain, this returns `None` and does not advance.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is wider than `S`.
+	#[inline]
+	pub fn read_signed<S>(&mut self, n: usize) -> Option<S>
+	where S: IsSigned {
+		check_width::<S>("read", n);
+		if n == 0 {
+			return Some(S::ZERO);
+		}
+		let field = self.take(n)?;
+		Some(match self.endian {
+			Endian::Little => field.load_signed_le(),
+			Endian::Big => field.load_signed_be(),
+		})
+	}
+
+	/// Discards `n` bits.
+	///
+	/// # Returns
+	///
+	/// Whether the stream contained `n` more bits. If it did not, the reader
+	/// does not advance.
+	#[inline]
+	pub fn skip(&mut self, n: usize) -> bool {
+		self.take(n).is_some()
+	}
+
+	/// Advances the reader to the next multiple of eight bits from the start
+	/// of the stream.
+	///
+	/// If the stream ends before that boundary, the reader moves to the end.
+	///
+	/// # Returns
+	///
+	/// The number of bits discarded.
+	#[inline]
+	pub fn align_to_byte(&mut self) -> usize {
+		let skip = (8 - (self.pos & 7)) & 7;
+		let skip = core::cmp::min(skip, self.remaining());
+		self.pos += skip;
+		skip
+	}
+
+	/// Consumes the reader, returning the bits not yet consumed.
+	#[inline]
+	pub fn into_inner(self) -> &'a BitSlice<O, T> {
+		self.as_bitslice()
+	}
+
+	/// Splits off the next `n` bits, advancing only if all of them exist.
+	#[inline]
+	fn take(&mut self, n: usize) -> Option<&'a BitSlice<O, T>> {
+		let end = self.pos.checked_add(n)?;
+		let field = self.bits.get(self.pos .. end)?;
+		self.pos = end;
+		Some(field)
+	}
+}
+
+/** A writing cursor that appends to a [`BitVec`].
+
+Every write extends the underlying bit-vector by exactly the number of bits it
+transfers, so the writer’s [`position`] is always the length of the buffer.
+
+# Type Parameters
+
+- `O`: The ordering of the destination bit-vector.
+- `T`: The storage type of the destination bit-vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::field::{BitReader, BitWriter, Endian};
+
+let mut writer = BitWriter::<Msb0, u8>::new(Endian::Big);
+writer.write_bits(3, 0b101u8);
+writer.write_bit(true);
+writer.write_signed(3, -1i8);
+assert_eq!(writer.align_to_byte(), 1);
+let bits = writer.into_bitvec();
+assert_eq!(bits.as_raw_slice(), &[0b101_1_111_0]);
+
+let mut reader = BitReader::new(&bits, Endian::Big);
+assert_eq!(reader.read_bits::<u8>(3), Some(0b101));
+```
+
+[`BitVec`]: crate::vec::BitVec
+[`position`]: Self::position
+**/
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct BitWriter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The buffer receiving written bits.
+	bits: BitVec<O, T>,
+	/// The element ordering of multi-bit writes.
+	endian: Endian,
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> BitWriter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Creates a writer with an empty buffer.
+	///
+	/// # Parameters
+	///
+	/// - `endian`: The element ordering used by all multi-bit writes.
+	#[inline]
+	pub fn new(endian: Endian) -> Self {
+		Self::from_bitvec(BitVec::new(), endian)
+	}
+
+	/// Creates a writer that appends to an existing bit-vector.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The buffer to extend. Its current contents are retained.
+	/// - `endian`: The element ordering used by all multi-bit writes.
+	#[inline]
+	pub fn from_bitvec(bits: BitVec<O, T>, endian: Endian) -> Self {
+		Self { bits, endian }
+	}
+
+	/// Gets the number of bits in the buffer.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn position(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Gets the element ordering used by multi-bit writes.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Views the bits written so far.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Consumes the writer, returning its buffer.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Appends a single bit.
+	#[inline]
+	pub fn write_bit(&mut self, bit: bool) {
+		self.bits.push(bit);
+	}
+
+	/// Appends an unsigned field of `n` bits.
+	///
+	/// # Parameters
+	///
+	/// - `n`: The width of the field. This may be zero, in which case nothing
+	///   is written.
+	/// - `value`: The field value. Only its `n` least significant bits are
+	///   written; any higher bits are discarded.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is wider than `M`.
+	#[inline]
+	pub fn write_bits<M>(&mut self, n: usize, value: M)
+	where M: BitMemory {
+		check_width::<M>("write", n);
+		if n == 0 {
+			return;
+		}
+		let endian = self.endian;
+		endian.store(self.grow(n), value);
+	}
+
+	/// Appends a two’s-complement field of `n` bits.
+	///
+	/// # Parameters
+	///
+	/// - `n`: The width of the field. This may be zero, in which case nothing
+	///   is written.
+	/// - `value`: The field value. Only its `n` least significant bits are
+	///   written, so values outside the range representable in `n` bits are
+	///   truncated.
+	///
+	/// # Panics
+	///
+	/// This panics if `n` is wider than `S`.
+	#[inline]
+	pub fn write_signed<S>(&mut self, n: usize, value: S)
+	where S: IsSigned {
+		check_width::<S>("write", n);
+		if n == 0 {
+			return;
+		}
+		let value: i128 = match value.try_into() {
+			Ok(value) => value,
+			Err(_) => unreachable!("Every signed integer fits in i128"),
+		};
+		let endian = self.endian;
+		endian.store(self.grow(n), value as u128);
+	}
+
+	/// Appends zeros until the buffer length is a multiple of eight.
+	///
+	/// # Returns
+	///
+	/// The number of padding bits written.
+	#[inline]
+	pub fn align_to_byte(&mut self) -> usize {
+		let pad = (8 - (self.bits.len() & 7)) & 7;
+		self.grow(pad);
+		pad
+	}
+
+	/// Extends the buffer by `n` zeroed bits, and returns them.
+	#[inline]
+	fn grow(&mut self, n: usize) -> &mut BitSlice<O, T> {
+		let len = self.bits.len();
+		self.bits.resize(len + n, false);
+		unsafe { self.bits.get_unchecked_mut(len ..) }
+	}
+}
+
+/// Asserts that a stream field fits in its destination integer.
+///
+/// # Panics
+///
+/// This panics if `n` is wider than [`N::BITS`].
+///
+/// [`N::BITS`]: funty::IsNumber::BITS
+#[inline]
+fn check_width<N>(action: &'static str, n: usize)
+where N: IsInteger {
+	if n > N::BITS as usize {
+		panic!(
+			"Cannot {} a {}-bit field with a {}-bit integer",
+			action,
+			n,
+			N::BITS
+		);
+	}
+}
@@ -1,5 +1,11 @@
 //! Unit tests for the `field` module.
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{
+	vec,
+	vec::Vec,
+};
+
 use super::*;
 use crate::prelude::*;
 
@@ -166,6 +172,22 @@ fn wide_load() {
 	assert_eq!(data[16 .. 144].load::<u128>(), !0u128);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn indexed_words() {
+	let bits = bits![Msb0, u8; 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 1];
+	assert_eq!(load_indexed::<_, _, u16>(&bits[1 ..]), 0b11_0001_0110);
+	let bits = bits![Lsb0, u16; 1, 0, 1, 1];
+	assert_eq!(load_indexed::<_, _, u8>(bits), 0b1101);
+
+	let mut data = [0u8; 3];
+	store_indexed(&mut data.view_bits_mut::<Msb0>()[5 .. 17], 0x0A5Cu16);
+	assert_eq!(data, [0b0000_0001, 0b1101_0010, 0b1000_0000]);
+	let mut data = [0u16; 2];
+	store_indexed(&mut data.view_bits_mut::<Lsb0>()[12 .. 20], 0xC3u8);
+	assert_eq!(data, [0x3000, 0x000C]);
+}
+
 #[test]
 #[should_panic]
 #[cfg(not(target_arch = "riscv64"))]
@@ -199,3 +221,394 @@ fn wrappers() {
 	assert_eq!(d.load_le::<u8>(), !0);
 	assert_eq!(d.load_be::<u8>(), !0);
 }
+
+#[test]
+fn stream_reader() {
+	let data = [0x12u8, 0x34, 0x56];
+	let mut reader = BitReader::new(data.view_bits::<Msb0>(), Endian::Big);
+
+	assert_eq!(reader.read_bits::<u8>(0), Some(0));
+	assert_eq!(reader.read_bits::<u8>(4), Some(0x1));
+	assert_eq!(reader.read_bits::<u16>(12), Some(0x234));
+	assert!(reader.skip(1));
+	assert_eq!(reader.align_to_byte(), 7);
+	assert_eq!(reader.position(), 24);
+	assert_eq!(reader.align_to_byte(), 0);
+	assert_eq!(reader.read_bit(), None);
+
+	let mut reader = BitReader::new(data.view_bits::<Lsb0>(), Endian::Little);
+	assert!(reader.skip(4));
+	assert_eq!(reader.read_bits::<u16>(12), Some(0x341));
+	assert_eq!(reader.read_signed::<i8>(4), Some(6));
+	assert_eq!(reader.read_signed::<i32>(3), Some(-3));
+	assert_eq!(reader.remaining(), 1);
+	assert!(!reader.skip(2));
+	assert_eq!(reader.read_bits::<u8>(2), None);
+	assert_eq!(reader.position(), 23);
+	assert_eq!(reader.align_to_byte(), 1);
+	assert!(reader.into_inner().is_empty());
+
+	let wide = [!0u64; 2];
+	let mut reader = BitReader::new(wide.view_bits::<Lsb0>(), Endian::Little);
+	assert_eq!(reader.read_signed::<i128>(128), Some(-1));
+}
+
+#[test]
+#[should_panic]
+fn stream_reader_too_wide() {
+	let data = [0u16; 2];
+	BitReader::new(data.view_bits::<Lsb0>(), Endian::Big).read_bits::<u8>(9);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn stream_round_trip() {
+	for &endian in &[Endian::Little, Endian::Big] {
+		let mut writer = BitWriter::<Lsb0, u16>::new(endian);
+		writer.write_bit(true);
+		writer.write_bits(13, 0x1ABCu16);
+		writer.write_bits(0, !0u8);
+		writer.write_signed(5, -7i8);
+		writer.write_signed(20, 300_000i32);
+		writer.write_bits(64, 0x0123_4567_89AB_CDEFu64);
+		assert_eq!(writer.position(), 103);
+		assert_eq!(writer.align_to_byte(), 1);
+
+		let bits = writer.into_bitvec();
+		let mut reader = BitReader::new(bits.as_bitslice(), endian);
+		assert_eq!(reader.read_bit(), Some(true));
+		assert_eq!(reader.read_bits::<u16>(13), Some(0x1ABC));
+		assert_eq!(reader.read_signed::<i8>(5), Some(-7));
+		assert_eq!(reader.read_signed::<i32>(20), Some(300_000));
+		assert_eq!(reader.read_bits::<u64>(64), Some(0x0123_4567_89AB_CDEF));
+		assert_eq!(reader.read_bit(), Some(false));
+		assert_eq!(reader.read_bit(), None);
+	}
+
+	let mut writer =
+		BitWriter::from_bitvec(bitvec![Msb0, u8; 1; 3], Endian::Big);
+	writer.write_bits(5, 0xFFu8);
+	writer.write_signed(4, 9i8);
+	assert_eq!(writer.as_bitslice().len(), 12);
+	assert_eq!(writer.into_bitvec().as_raw_slice(), &[0xFF, 0x90]);
+}
+
+#[test]
+fn signed_fields() {
+	macro_rules! check {
+		($($o:ty, $t:ty => $($s:ty),+);+ $(;)?) => { $( $(
+			let mut data = [<$t>::ZERO; 10];
+			let bits = data.view_bits_mut::<$o>();
+			let width = <$s>::BITS as usize;
+			for &start in &[0, 1, 7, 13] {
+				for len in 1 ..= width {
+					let field = &mut bits[start ..][.. len];
+					let min = if len == width {
+						<$s>::MIN
+					} else {
+						-(1 << (len - 1))
+					};
+					let max = !min;
+
+					for &value in &[min, -1, 0, max] {
+						field.store_signed_le(value);
+						assert_eq!(field.load_signed_le::<$s>(), value);
+						field.store_signed_be(value);
+						assert_eq!(field.load_signed_be::<$s>(), value);
+						field.store_signed(value);
+						assert_eq!(field.load_signed::<$s>(), value);
+					}
+
+					//  The raw bits match an unsigned store of the truncated
+					//  value, and the high bit becomes the sign.
+					field.store_le(!0u64);
+					assert_eq!(field.load_signed_le::<$s>(), -1);
+					field.store_be(1u64 << (len - 1));
+					assert_eq!(field.load_signed_be::<$s>(), min);
+				}
+			}
+		)+ )+ };
+	}
+
+	check! {
+		Lsb0, u8 => i8, i16, i32, i64;
+		Lsb0, u16 => i8, i16, i32, i64;
+		Lsb0, u32 => i8, i16, i32, i64;
+		Lsb0, usize => i8, i16, i32, i64;
+		Msb0, u8 => i8, i16, i32, i64;
+		Msb0, u16 => i8, i16, i32, i64;
+		Msb0, u32 => i8, i16, i32, i64;
+		Msb0, usize => i8, i16, i32, i64;
+	}
+}
+
+#[test]
+#[should_panic]
+fn signed_store_range() {
+	let mut data = 0u16;
+	data.view_bits_mut::<Msb0>()[3 .. 9].store_signed_be(-33i8);
+}
+
+#[test]
+#[should_panic]
+fn signed_load_width() {
+	let data = 0u16;
+	data.view_bits::<Lsb0>()[.. 9].load_signed_le::<i8>();
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn arithmetic() {
+	use core::cmp::Ordering;
+
+	/// Compares the arithmetic of misaligned, 90-bit, numbers against `u128`.
+	fn check<O1, T1, O2, T2>(endian: Endian)
+	where
+		O1: BitOrder,
+		T1: BitStore,
+		O2: BitOrder,
+		T2: BitStore,
+		BitSlice<O1, T1>: BitField,
+		BitSlice<O2, T2>: BitField,
+	{
+		const WIDTH: usize = 90;
+		let limit = 1u128 << WIDTH;
+		let values = [
+			0,
+			1,
+			0xFF,
+			u64::MAX as u128,
+			1 << 64,
+			(1 << 89) + 12345,
+			0x0123_4567_89AB_CDEF_0123_4567 % limit,
+			limit - 1,
+		];
+
+		let store = |bits: &mut BitSlice<O1, T1>, value: u128| match endian {
+			Endian::Little => bits.store_le(value),
+			Endian::Big => bits.store_be(value),
+		};
+		let load = |bits: &BitSlice<O1, T1>| match endian {
+			Endian::Little => bits.load_le::<u128>(),
+			Endian::Big => bits.load_be::<u128>(),
+		};
+
+		for &a in &values {
+			let mut lhs = BitVec::<O1, T1>::repeat(false, WIDTH + 5);
+			store(&mut lhs[3 .. 3 + WIDTH], a);
+			//  Each operation works on its own copy of the misaligned operand.
+			let fresh = || lhs.clone();
+			let lhs = &lhs[3 .. 3 + WIDTH];
+			assert_eq!(
+				lhs.to_u64(endian),
+				Some(a as u64).filter(|_| a >> 64 == 0)
+			);
+
+			for &b in &values {
+				let mut rhs = BitVec::<O2, T2>::repeat(false, WIDTH + 20);
+				let rhs = &mut rhs[7 ..][.. WIDTH];
+				match endian {
+					Endian::Little => rhs.store_le(b),
+					Endian::Big => rhs.store_be(b),
+				}
+				let rhs = &*rhs;
+
+				assert_eq!(lhs.cmp_numeric(rhs, endian), a.cmp(&b));
+
+				let mut sum = fresh();
+				let overflow =
+					sum[3 .. 3 + WIDTH].overflowing_add_assign(rhs, endian);
+				assert_eq!(load(&sum[3 .. 3 + WIDTH]), (a + b) % limit);
+				assert_eq!(overflow, a + b >= limit);
+
+				let mut diff = fresh();
+				let overflow =
+					diff[3 .. 3 + WIDTH].overflowing_sub_assign(rhs, endian);
+				assert_eq!(
+					load(&diff[3 .. 3 + WIDTH]),
+					a.wrapping_sub(b) % limit
+				);
+				assert_eq!(overflow, b > a);
+
+				let mut prod = fresh();
+				let overflow =
+					prod[3 .. 3 + WIDTH].overflowing_mul_assign(rhs, endian);
+				assert_eq!(
+					load(&prod[3 .. 3 + WIDTH]),
+					a.wrapping_mul(b) % limit
+				);
+				assert_eq!(
+					overflow,
+					a.checked_mul(b).map_or(true, |p| p >= limit)
+				);
+			}
+		}
+	}
+
+	for &endian in &[Endian::Little, Endian::Big] {
+		check::<Lsb0, u8, Msb0, u16>(endian);
+		check::<Msb0, u16, Lsb0, u8>(endian);
+		check::<Msb0, u32, Msb0, usize>(endian);
+		check::<Lsb0, usize, Lsb0, u32>(endian);
+	}
+
+	//  Excess bits in the right operand are overflow, not silently ignored.
+	let mut data = [0u8; 2];
+	let wide = [0u8, 0, 1];
+	let (lhs, rhs) = (data.view_bits_mut::<Lsb0>(), wide.view_bits::<Lsb0>());
+	assert!(lhs.overflowing_add_assign(rhs, Endian::Little));
+	assert!(lhs.overflowing_sub_assign(rhs, Endian::Little));
+	assert_eq!(lhs.cmp_numeric(rhs, Endian::Little), Ordering::Less);
+	assert_eq!(rhs.cmp_numeric(lhs, Endian::Big), Ordering::Greater);
+	assert!(BitSlice::<Lsb0, u8>::empty().to_u64(Endian::Big) == Some(0));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn word_stream() {
+	fn check<O, T, M>(bits: &mut BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+		BitSlice<O, T>: BitField,
+		M: BitMemory,
+	{
+		let width = M::BITS as usize;
+		let lsb0 = crate::devel::match_order::<O, Lsb0>();
+		let expected = bits
+			.chunks(width)
+			.map(|chunk| {
+				chunk.iter().by_val().enumerate().fold(
+					0u128,
+					|word, (idx, bit)| {
+						let shamt = if lsb0 { idx } else { width - 1 - idx };
+						word | (bit as u128) << shamt
+					},
+				)
+			})
+			.collect::<Vec<_>>();
+
+		let words = bits.iter_words::<M>();
+		assert_eq!(words.len(), expected.len());
+		assert_eq!(words.tail_width(), match bits.len() % width {
+			0 if bits.is_empty() => 0,
+			0 => width,
+			n => n,
+		});
+		let words = words.collect::<Vec<M>>();
+		let actual = words
+			.iter()
+			.map(|&word| resize::<M, u128>(word))
+			.collect::<Vec<_>>();
+		assert_eq!(actual, expected);
+
+		let original = bits.iter().by_val().collect::<BitVec>();
+		bits.set_all(false);
+		assert_eq!(bits.store_words(words.iter().copied()), words.len());
+		assert_eq!(bits, original);
+
+		let inverted = words.iter().map(|&word| !word).collect::<Vec<M>>();
+		bits.store_words(inverted);
+		assert_eq!(bits, !original);
+	}
+
+	let mut data = [0u64; 6];
+	let words = dvl::xorshift(0x2545_F491_4F6C_DD1D);
+	for (elem, word) in data.iter_mut().zip(words) {
+		*elem = word;
+	}
+
+	for &(start, end) in &[(0, 384), (3, 384), (5, 300), (17, 30), (9, 9)] {
+		let mut bytes = data;
+		let bits = &mut bytes.view_bits_mut::<Lsb0>()[start .. end];
+		check::<_, _, u8>(bits);
+		check::<_, _, u16>(bits);
+		check::<_, _, u32>(bits);
+		check::<_, _, u64>(bits);
+		check::<_, _, u128>(bits);
+
+		let mut bytes = data;
+		let bits = &mut bytes.view_bits_mut::<Msb0>()[start .. end];
+		check::<_, _, u8>(bits);
+		check::<_, _, u32>(bits);
+		check::<_, _, u64>(bits);
+		check::<_, _, u128>(bits);
+
+		let mut bytes = [0u8; 48];
+		for (byte, src) in
+			bytes.iter_mut().zip(data.view_bits::<Lsb0>().chunks(8))
+		{
+			*byte = src.load_le();
+		}
+		check::<_, _, u64>(&mut bytes.view_bits_mut::<Msb0>()[start .. end]);
+		check::<_, _, u16>(&mut bytes.view_bits_mut::<Lsb0>()[start .. end]);
+		check::<_, _, u128>(&mut bytes.view_bits_mut::<Lsb0>()[start .. end]);
+	}
+
+	//  Aligned views yield their elements.
+	let elems = [0x0123_4567u32, 0x89AB_CDEF];
+	let words = elems.view_bits::<Msb0>().iter_words::<u32>();
+	assert_eq!(words.collect::<Vec<_>>(), elems);
+	let words = elems.view_bits::<Lsb0>().iter_words::<u32>();
+	assert_eq!(words.collect::<Vec<_>>(), elems);
+
+	//  Writing stops with the words, and the remaining bits are untouched.
+	let mut data = [0u8; 3];
+	let bits = data.view_bits_mut::<Lsb0>();
+	assert_eq!(bits[2 ..].store_words(vec![0xFFu8]), 1);
+	assert_eq!(data, [0xFC, 0x03, 0]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn field_iteration() {
+	let data = [0x12u8, 0x34, 0x56];
+
+	let bits = data.view_bits::<Lsb0>();
+	assert_eq!(
+		bits.iter_fields::<u8>(4, Endian::Little)
+			.collect::<Vec<_>>(),
+		[2, 1, 4, 3, 6, 5]
+	);
+	assert_eq!(
+		bits.iter_fields::<u16>(12, Endian::Little)
+			.collect::<Vec<_>>(),
+		[0x412, 0x563]
+	);
+
+	let bits = data.view_bits::<Msb0>();
+	assert_eq!(
+		bits.iter_fields::<u16>(12, Endian::Big).collect::<Vec<_>>(),
+		[0x123, 0x456]
+	);
+	//  0b00010_01000_11010_00101, with 0b0110 left over.
+	let mut fields = bits.iter_fields::<u8>(5, Endian::Big);
+	assert_eq!(fields.len(), 4);
+	assert_eq!(fields.remainder(), bits![0, 1, 1, 0]);
+	assert_eq!(fields.clone().rev().collect::<Vec<_>>(), [5, 26, 8, 2]);
+	assert_eq!(fields.nth(1), Some(8));
+	assert_eq!(fields.nth_back(0), Some(5));
+	assert_eq!(fields.next(), Some(26));
+	assert!(fields.next_back().is_none());
+
+	let mut out = [0u8; 3];
+	let bits = out.view_bits_mut::<Msb0>();
+	assert_eq!(bits.store_fields(5, Endian::Big, vec![2u8, 8, 26, 5]), 4);
+	assert_eq!(out, [0x12, 0x34, 0x50]);
+	//  Running out of values leaves the rest of the bit-slice alone.
+	let bits = out.view_bits_mut::<Lsb0>();
+	assert_eq!(bits.store_fields(4, Endian::Little, vec![0xFu8]), 1);
+	assert_eq!(out, [0x1F, 0x34, 0x50]);
+
+	let mut bv = BitVec::<Msb0, u8>::new();
+	bv.extend_fields(5, Endian::Big, vec![2u8, 8]);
+	//  Without an exact size hint, the bit-vector grows one field at a time.
+	bv.extend_fields(5, Endian::Big, vec![26u8, 5].into_iter().filter(|_| true));
+	assert_eq!(bv, data.view_bits::<Msb0>()[.. 20]);
+}
+
+#[test]
+#[should_panic]
+fn field_width_too_wide() {
+	bits![0; 32].iter_fields::<u8>(9, Endian::Big);
+}
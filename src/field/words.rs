@@ -0,0 +1,306 @@
+/*! Word-at-a-time transfer between a [`BitSlice`] and integers.
+
+[`BitField`] moves one value per call, and every call walks the [`domain`] of
+the region it is given. Kernels that combine two bit-slices which begin at
+different offsets within their elements need a stream of whole integers
+instead. This module reads a bit-slice as a sequence of `M` integers, loading
+its memory elements directly rather than walking a domain for each word, and
+writes a sequence of `M` integers into a bit-slice.
+
+Each word has the layout that an `M` element has under the bit-slice’s
+[`BitOrder`]. Under [`Lsb0`], the first bit of a word is its least significant
+bit; under [`Msb0`], it is its most significant bit. When the bit-slice is an
+aligned view of `[M]`, the words are exactly the memory elements.
+
+[`BitField`]: crate::field::BitField
+[`BitOrder`]: crate::order::BitOrder
+[`BitSlice`]: crate::slice::BitSlice
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+[`domain`]: crate::slice::BitSlice::domain
+!*/
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+	marker::PhantomData,
+};
+
+use funty::IsNumber;
+
+use super::resize;
+use crate::{
+	devel as dvl,
+	field::BitField,
+	mem::BitMemory,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Iterates over the bit-slice as a sequence of `M` integers.
+	///
+	/// Each word holds the next `M::BITS` bits of the bit-slice, with the
+	/// layout an `M` element has under the ordering `O`. The words are
+	/// assembled by shifting together the live bits of each memory element,
+	/// so the bit-slice may begin at any index within its first element.
+	///
+	/// If the length of the bit-slice is not a multiple of `M::BITS`, the
+	/// final word is padded with zeros in the positions that follow its live
+	/// bits. [`Words::tail_width`] reports how many of its bits are live.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type of each word.
+	///
+	/// # Returns
+	///
+	/// An iterator over the words of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xA5u8, 0x3C, 0xFF];
+	/// let bits = data.view_bits::<Lsb0>();
+	/// let words = bits[4 ..].iter_words::<u8>().collect::<Vec<_>>();
+	/// assert_eq!(words, [0xCA, 0xF3, 0x0F]);
+	///
+	/// let bits = data.view_bits::<Msb0>();
+	/// let mut words = bits[4 .. 22].iter_words::<u8>();
+	/// assert_eq!(words.tail_width(), 2);
+	/// assert_eq!(words.next(), Some(0x53));
+	/// assert_eq!(words.next(), Some(0xCF));
+	/// assert_eq!(words.next(), Some(0xC0));
+	/// assert!(words.next().is_none());
+	/// ```
+	///
+	/// [`Words::tail_width`]: crate::field::Words::tail_width
+	#[inline]
+	pub fn iter_words<M>(&self) -> Words<O, T, M>
+	where M: BitMemory {
+		Words {
+			bits: self,
+			_mem: PhantomData,
+		}
+	}
+
+	/// Writes a sequence of `M` integers into the bit-slice.
+	///
+	/// This is the inverse of [`.iter_words()`]: each word fills the next
+	/// `M::BITS` bits of the bit-slice, and a bit-slice written with the words
+	/// that `.iter_words()` produces is unchanged. If the final word is only
+	/// partly used, its leading live bits are written and the rest discarded.
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type of each word.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `words`: The words to write. Writing stops when either the bit-slice
+	///   or the words run out; bits past the last word are not modified.
+	///
+	/// # Returns
+	///
+	/// The number of words written, including a partially written final word.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// let words = [0x53u8, 0xCF, 0xC0];
+	/// assert_eq!(bits[4 ..].store_words(words.iter().copied()), 3);
+	/// assert_eq!(data, [0x05, 0x3C, 0xFC]);
+	/// ```
+	///
+	/// [`.iter_words()`]: Self::iter_words
+	#[inline]
+	pub fn store_words<M, I>(&mut self, words: I) -> usize
+	where
+		M: BitMemory,
+		I: IntoIterator<Item = M>,
+	{
+		let width = M::BITS as usize;
+		let lsb0 = dvl::match_order::<O, Lsb0>();
+		let (mut pos, mut count) = (0, 0);
+		let mut words = words.into_iter();
+		while pos < self.len() {
+			let word = match words.next() {
+				Some(word) => word,
+				None => break,
+			};
+			let len = cmp::min(width, self.len() - pos);
+			let dest = unsafe { self.get_unchecked_mut(pos .. pos + len) };
+			//  These are the layouts that `.iter_words()` produces for whole
+			//  words. A short final word keeps its live bits at its first edge,
+			//  which under `Msb0` is the most significant.
+			if lsb0 {
+				dest.store_le(word);
+			}
+			else if len == width {
+				dest.store_be(word);
+			}
+			else {
+				dest.store_be(word >> (width - len));
+			}
+			pos += len;
+			count += 1;
+		}
+		count
+	}
+}
+
+/** An iterator over a [`BitSlice`] as a sequence of `M` integers.
+
+This is produced by [`BitSlice::iter_words`].
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::iter_words`]: crate::slice::BitSlice::iter_words
+**/
+#[derive(Clone, Debug)]
+pub struct Words<'a, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	/// The bits not yet yielded.
+	bits: &'a BitSlice<O, T>,
+	/// The word type.
+	_mem: PhantomData<M>,
+}
+
+impl<'a, O, T, M> Words<'a, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	/// Gets the number of live bits in the final word.
+	///
+	/// # Returns
+	///
+	/// A number in `1 ..= M::BITS`, or zero if no words remain.
+	#[inline]
+	pub fn tail_width(&self) -> usize {
+		match self.bits.len() {
+			0 => 0,
+			len => (len - 1) % M::BITS as usize + 1,
+		}
+	}
+}
+
+impl<O, T, M> Iterator for Words<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	type Item = M;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.bits.is_empty() {
+			return None;
+		}
+		let width = M::BITS as usize;
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		let lsb0 = dvl::match_order::<O, Lsb0>();
+		let total = cmp::min(width, self.bits.len());
+		let (addr, head, _) = self.bits.as_bitspan().raw_parts();
+		let (mut elem, head) = (addr.to_const(), head.value() as usize);
+		let load = |elem: *const T| unsafe { &*elem }.load_value();
+		//  Places the `len` bits of `raw` that begin `from` bits past the
+		//  first edge of an element, `filled` bits past the first edge of the
+		//  word. Under `Lsb0` the first edge is the least significant one. A
+		//  short final word is padded after its live bits.
+		#[allow(clippy::op_ref)]
+		let place = |raw: T::Mem, from: usize, len: usize, filled: usize| {
+			//  Clear the bits outside the piece and bring it down to the least
+			//  significant edge.
+			let front = if lsb0 { bits - from - len } else { from };
+			let piece = resize::<T::Mem, M>(raw << &front >> &(bits - len));
+			if lsb0 {
+				piece << filled as u8
+			}
+			else {
+				piece << (width - filled - len) as u8
+			}
+		};
+
+		let mut filled = cmp::min(total, bits - head);
+		let mut word = place(load(elem), head, filled, 0);
+		if head + filled == bits {
+			elem = unsafe { elem.add(1) };
+		}
+		while total - filled >= bits {
+			word |= place(load(elem), 0, bits, filled);
+			elem = unsafe { elem.add(1) };
+			filled += bits;
+		}
+		if filled < total {
+			word |= place(load(elem), 0, total - filled, filled);
+		}
+		self.bits = unsafe { self.bits.get_unchecked(total ..) };
+		Some(word)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+}
+
+impl<O, T, M> ExactSizeIterator for Words<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		let width = M::BITS as usize;
+		let len = self.bits.len();
+		len / width + (len % width != 0) as usize
+	}
+}
+
+impl<O, T, M> FusedIterator for Words<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+	M: BitMemory,
+{
+}
+
+/// Produces a mask of the `len` least significant bits, for `len` in
+/// `1 ..= 64`.
+#[inline]
+pub(super) fn mask(len: usize) -> u64 {
+	!0 >> (64 - len)
+}
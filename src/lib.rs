@@ -291,6 +291,18 @@ pub mod view;
 #[cfg(feature = "alloc")]
 pub mod boxed;
 
+#[cfg(feature = "alloc")]
+pub mod compress;
+
+#[cfg(feature = "alloc")]
+pub mod matrix;
+
+#[cfg(feature = "alloc")]
+pub mod rank;
+
+#[cfg(feature = "alloc")]
+pub mod set;
+
 #[cfg(feature = "alloc")]
 pub mod vec;
 
@@ -0,0 +1,837 @@
+/*! Two-dimensional matrices of bits.
+
+Bitsliced cryptography, bit-plane image processing, and linear algebra over the
+two-element field GF(2) all treat a block of memory as a grid of bits, and all
+need to move between row and column views of that grid. This module provides the
+[`BitMatrix`] type, which stores a grid in one [`BitVec`] in row-major order, so
+that each row is a contiguous [`BitSlice`].
+
+# Layout
+
+Row `r` of a matrix with `cols` columns occupies the indices
+`r * cols .. (r + 1) * cols` of the underlying bit-vector. Rows are not padded
+to memory-element boundaries, so the bit-vector is exactly `rows * cols` bits
+long and can be moved into and out of the matrix without copying. Operations
+that combine whole rows use the batched [`BitSlice`] operators, which work on
+memory elements even when rows begin part-way through one.
+
+Columns are not contiguous, and so cannot be viewed as a [`BitSlice`]. The
+[`.column()`] method walks one column by striding through the rows; code that
+reads many columns should [`.transpose()`] the matrix once and then read rows.
+
+# Transposition
+
+For the [`Lsb0`] and [`Msb0`] orderings, [`.transpose()`] moves 64 × 64-bit
+tiles at a time. Each tile is loaded as 64 `u64` rows, transposed in registers
+by the classic recursive block swap (exchanging the off-diagonal 32 × 32
+blocks, then the 16 × 16 blocks within each of those, down to single bits),
+and stored as 64 rows of the output. Other orderings move one bit at a time.
+
+[`BitMatrix`]: crate::matrix::BitMatrix
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+[`.column()`]: crate::matrix::BitMatrix::column
+[`.transpose()`]: crate::matrix::BitMatrix::transpose
+!*/
+
+#![cfg(feature = "alloc")]
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FusedIterator,
+};
+
+use crate::{
+	devel as dvl,
+	field,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The side length of the square tiles moved by [`BitMatrix::transpose`].
+const TILE: usize = 64;
+
+/** A rectangular grid of bits, stored row-major in a [`BitVec`].
+
+# Type Parameters
+
+- `O`: The ordering of bits within memory elements of the storage.
+- `T`: The memory element type of the storage.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::matrix::BitMatrix;
+
+let mut mat = BitMatrix::<Lsb0, u8>::new(2, 3);
+mat.set(0, 2, true);
+mat.set(1, 0, true);
+assert_eq!(mat.row(0), bits![0, 0, 1]);
+assert_eq!(mat.column(0).collect::<BitVec>(), bits![0, 1]);
+
+let tr = mat.transpose();
+assert_eq!((tr.rows(), tr.cols()), (3, 2));
+assert_eq!(tr.row(2), bits![1, 0]);
+```
+
+[`BitVec`]: crate::vec::BitVec
+**/
+pub struct BitMatrix<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The matrix contents, one row after another.
+	bits: BitVec<O, T>,
+	/// The number of rows.
+	rows: usize,
+	/// The number of columns, which is also the length of each row.
+	cols: usize,
+}
+
+impl<O, T> BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs a matrix with every bit cleared.
+	///
+	/// # Parameters
+	///
+	/// - `rows`: The number of rows.
+	/// - `cols`: The number of columns.
+	///
+	/// # Panics
+	///
+	/// This panics if `rows * cols` overflows.
+	#[inline]
+	pub fn new(rows: usize, cols: usize) -> Self {
+		let len = rows
+			.checked_mul(cols)
+			.expect("Matrix dimensions overflow `usize`");
+		Self {
+			bits: BitVec::repeat(false, len),
+			rows,
+			cols,
+		}
+	}
+
+	/// Constructs the square identity matrix.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let id = BitMatrix::<Msb0, u8>::identity(3);
+	/// assert_eq!(id.row(1), bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn identity(size: usize) -> Self {
+		let mut out = Self::new(size, size);
+		for idx in 0 .. size {
+			out.set(idx, idx, true);
+		}
+		out
+	}
+
+	/// Wraps a bit-vector holding a matrix in row-major order.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The rows of the matrix, one after another.
+	/// - `rows`: The number of rows.
+	/// - `cols`: The number of columns.
+	///
+	/// # Panics
+	///
+	/// This panics if `bits` does not have exactly `rows * cols` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mat = BitMatrix::from_bitvec(bitvec![0, 1, 1, 1, 0, 1], 2, 3);
+	/// assert_eq!(mat.row(1), bits![1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn from_bitvec(bits: BitVec<O, T>, rows: usize, cols: usize) -> Self {
+		assert_eq!(
+			Some(bits.len()),
+			rows.checked_mul(cols),
+			"A {} × {} matrix requires {} × {} bits",
+			rows,
+			cols,
+			rows,
+			cols,
+		);
+		Self { bits, rows, cols }
+	}
+
+	/// Removes the matrix wrapper, leaving its rows in one bit-vector.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Views the whole matrix, in row-major order.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Gets the number of rows.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Reads the bit at a row and column.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` or `col` is out of bounds.
+	#[inline]
+	pub fn get(&self, row: usize, col: usize) -> bool {
+		self.assert_col(col);
+		self.row(row)[col]
+	}
+
+	/// Writes the bit at a row and column.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` or `col` is out of bounds.
+	#[inline]
+	pub fn set(&mut self, row: usize, col: usize, value: bool) {
+		self.assert_col(col);
+		self.row_mut(row).set(col, value);
+	}
+
+	/// Views one row of the matrix.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	#[inline]
+	pub fn row(&self, row: usize) -> &BitSlice<O, T> {
+		self.assert_row(row);
+		let start = row * self.cols;
+		unsafe { self.bits.get_unchecked(start .. start + self.cols) }
+	}
+
+	/// Mutably views one row of the matrix.
+	///
+	/// # Panics
+	///
+	/// This panics if `row` is out of bounds.
+	#[inline]
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<O, T> {
+		self.assert_row(row);
+		let start = row * self.cols;
+		unsafe { self.bits.get_unchecked_mut(start .. start + self.cols) }
+	}
+
+	/// Iterates over the bits of one column, from the first row to the last.
+	///
+	/// Columns are not contiguous in memory, so this cannot produce a
+	/// [`BitSlice`]. Collect the iterator into a [`BitVec`], or
+	/// [`.transpose()`] the matrix, to work with columns as bit-slices.
+	///
+	/// # Panics
+	///
+	/// This panics if `col` is out of bounds.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`BitVec`]: crate::vec::BitVec
+	/// [`.transpose()`]: Self::transpose
+	#[inline]
+	pub fn column(&self, col: usize) -> Column<O, T> {
+		self.assert_col(col);
+		//  A matrix with no rows has no bits, so the column starts at the end.
+		let start = cmp::min(col, self.bits.len());
+		Column {
+			bits: unsafe { self.bits.get_unchecked(start ..) },
+			stride: self.cols,
+			front: 0,
+			back: self.rows,
+		}
+	}
+
+	/// Exchanges two rows.
+	///
+	/// # Panics
+	///
+	/// This panics if either row is out of bounds.
+	#[inline]
+	pub fn swap_rows(&mut self, a: usize, b: usize) {
+		self.assert_row(a);
+		self.assert_row(b);
+		if a == b {
+			return;
+		}
+		let (lo, hi) = (cmp::min(a, b), cmp::max(a, b));
+		let cols = self.cols;
+		let (head, rest) = self.bits.split_at_mut(hi * cols);
+		let (lo, hi) =
+			(&mut head[lo * cols .. (lo + 1) * cols], &mut rest[.. cols]);
+		//  Three batched exclusive-ors avoid a temporary and a bit-wise swap.
+		*lo ^= &*hi;
+		*hi ^= &*lo;
+		*lo ^= &*hi;
+	}
+
+	/// Adds one row to another, over GF(2).
+	///
+	/// This is `row(dst) ^= row(src)`, the elementary operation of Gaussian
+	/// elimination.
+	///
+	/// # Panics
+	///
+	/// This panics if either row is out of bounds, or if they are the same
+	/// row.
+	#[inline]
+	pub fn add_row(&mut self, dst: usize, src: usize) {
+		self.assert_row(dst);
+		self.assert_row(src);
+		assert_ne!(dst, src, "Cannot add a row to itself");
+		let cols = self.cols;
+		let (lo, hi) = (cmp::min(dst, src), cmp::max(dst, src));
+		let (head, rest) = self.bits.split_at_mut(hi * cols);
+		let (lo, hi) =
+			(&mut head[lo * cols .. (lo + 1) * cols], &mut rest[.. cols]);
+		if dst < src {
+			*lo ^= &*hi;
+		}
+		else {
+			*hi ^= &*lo;
+		}
+	}
+
+	/// Multiplies two matrices over GF(2).
+	///
+	/// Each row of the product is the exclusive-or of the rows of `rhs`
+	/// selected by the `1` bits in the matching row of `self`, so the work is
+	/// done with batched row operations.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: An `m × n` matrix.
+	/// - `rhs`: An `n × p` matrix.
+	///
+	/// # Returns
+	///
+	/// The `m × p` product `self × rhs`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self.cols()` is not `rhs.rows()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let a = BitMatrix::from_bitvec(bitvec![1, 1, 0, 1], 2, 2);
+	/// let b = BitMatrix::from_bitvec(bitvec![1, 0, 1, 1], 2, 2);
+	/// let c = a.multiply(&b);
+	/// assert_eq!(c.as_bitslice(), bits![0, 1, 1, 1]);
+	/// ```
+	pub fn multiply<O2, T2>(&self, rhs: &BitMatrix<O2, T2>) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			self.cols, rhs.rows,
+			"Cannot multiply a {} × {} matrix by a {} × {} matrix",
+			self.rows, self.cols, rhs.rows, rhs.cols,
+		);
+		let mut out = Self::new(self.rows, rhs.cols);
+		for row in 0 .. self.rows {
+			let dst = out.row_mut(row);
+			for idx in self.row(row).iter_ones() {
+				*dst ^= rhs.row(idx);
+			}
+		}
+		out
+	}
+
+	/// Reduces the matrix to reduced row echelon form, by Gaussian elimination
+	/// over GF(2).
+	///
+	/// After this, the first `1` bit of each nonzero row is the only `1` bit
+	/// in its column, each such bit is to the right of the one in the row
+	/// above, and all zero rows are at the bottom.
+	///
+	/// # Returns
+	///
+	/// The rank of the matrix, which is the number of nonzero rows that
+	/// remain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut mat =
+	///   BitMatrix::from_bitvec(bitvec![0, 1, 1, 1, 1, 0, 1, 0, 1], 3, 3);
+	/// assert_eq!(mat.row_reduce(), 2);
+	/// assert_eq!(mat.as_bitslice(), bits![1, 0, 1, 0, 1, 1, 0, 0, 0]);
+	/// ```
+	pub fn row_reduce(&mut self) -> usize {
+		let mut rank = 0;
+		for col in 0 .. self.cols {
+			if rank == self.rows {
+				break;
+			}
+			let pivot = match (rank .. self.rows).find(|&row| self.get(row, col))
+			{
+				Some(pivot) => pivot,
+				None => continue,
+			};
+			self.swap_rows(rank, pivot);
+			for row in 0 .. self.rows {
+				if row != rank && self.get(row, col) {
+					self.add_row(row, rank);
+				}
+			}
+			rank += 1;
+		}
+		rank
+	}
+
+	/// Computes the rank of the matrix over GF(2).
+	///
+	/// This runs [`.row_reduce()`] on a copy of the matrix.
+	///
+	/// [`.row_reduce()`]: Self::row_reduce
+	#[inline]
+	pub fn rank(&self) -> usize {
+		self.clone().row_reduce()
+	}
+
+	/// Produces the transpose of the matrix.
+	///
+	/// For the [`Lsb0`] and [`Msb0`] orderings, this moves 64 × 64-bit tiles at
+	/// a time, as described in the [module documentation]. Other orderings move
+	/// one bit at a time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mat = BitMatrix::from_bitvec(bitvec![1, 1, 0, 0, 0, 1], 2, 3);
+	/// let tr = mat.transpose();
+	/// assert_eq!(tr.as_bitslice(), bits![1, 0, 1, 0, 0, 1]);
+	/// assert_eq!(tr.transpose(), mat);
+	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	/// [module documentation]: crate::matrix
+	pub fn transpose(&self) -> Self {
+		let mut out = Self::new(self.cols, self.rows);
+		let (rows, cols) = (self.rows, self.cols);
+		if dvl::match_order::<O, Lsb0>() || dvl::match_order::<O, Msb0>() {
+			let dst = out.bits.as_mut_bitslice();
+			transpose_tiles(self.as_bitslice(), dst, rows, cols);
+		}
+		else {
+			for row in 0 .. rows {
+				for col in self.row(row).iter_ones() {
+					out.set(col, row, true);
+				}
+			}
+		}
+		out
+	}
+
+	/// Asserts that a row index is in bounds.
+	#[inline]
+	fn assert_row(&self, row: usize) {
+		assert!(
+			row < self.rows,
+			"Row index {} out of range for {} rows",
+			row,
+			self.rows
+		);
+	}
+
+	/// Asserts that a column index is in bounds.
+	#[inline]
+	fn assert_col(&self, col: usize) {
+		assert!(
+			col < self.cols,
+			"Column index {} out of range for {} columns",
+			col,
+			self.cols
+		);
+	}
+}
+
+impl<O, T> Clone for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			rows: self.rows,
+			cols: self.cols,
+		}
+	}
+}
+
+impl<O, T> Eq for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> PartialEq for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.rows == other.rows
+			&& self.cols == other.cols
+			&& self.bits == other.bits
+	}
+}
+
+impl<O, T> Hash for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		self.rows.hash(state);
+		self.cols.hash(state);
+		self.bits.hash(state);
+	}
+}
+
+impl<O, T> Debug for BitMatrix<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "BitMatrix({} × {}) ", self.rows, self.cols)?;
+		fmt.debug_list()
+			.entries((0 .. self.rows).map(|row| self.row(row)))
+			.finish()
+	}
+}
+
+/** Iterates over the bits of one column of a [`BitMatrix`].
+
+This is produced by [`BitMatrix::column`].
+
+[`BitMatrix`]: crate::matrix::BitMatrix
+[`BitMatrix::column`]: crate::matrix::BitMatrix::column
+**/
+#[derive(Clone, Debug)]
+pub struct Column<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The matrix contents, starting at the first bit of the column.
+	bits: &'a BitSlice<O, T>,
+	/// The distance between successive bits of the column.
+	stride: usize,
+	/// The next row to yield from the front.
+	front: usize,
+	/// One past the next row to yield from the back.
+	back: usize,
+}
+
+impl<O, T> Iterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = bool;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		let bit = unsafe { *self.bits.get_unchecked(self.front * self.stride) };
+		self.front += 1;
+		Some(bit)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.front = cmp::min(self.front.saturating_add(n), self.back);
+		self.next()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(unsafe { *self.bits.get_unchecked(self.back * self.stride) })
+	}
+}
+
+impl<O, T> ExactSizeIterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> FusedIterator for Column<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/// Transposes the `rows × cols` matrix in `src` into the `cols × rows` matrix
+/// in `dst`, one square tile at a time.
+///
+/// Each chunk is loaded and stored with index `k` in bit `k` of its register.
+fn transpose_tiles<O, T>(
+	src: &BitSlice<O, T>,
+	dst: &mut BitSlice<O, T>,
+	rows: usize,
+	cols: usize,
+) where
+	O: BitOrder,
+	T: BitStore,
+{
+	let mut tile = [0u64; TILE];
+	for row in (0 .. rows).step_by(TILE) {
+		let height = cmp::min(TILE, rows - row);
+		for col in (0 .. cols).step_by(TILE) {
+			let width = cmp::min(TILE, cols - col);
+			for (idx, word) in tile.iter_mut().enumerate() {
+				*word = if idx < height {
+					let start = (row + idx) * cols + col;
+					field::load_indexed(&src[start .. start + width])
+				}
+				else {
+					0
+				};
+			}
+			transpose64(&mut tile);
+			for (idx, &word) in tile[.. width].iter().enumerate() {
+				let start = (col + idx) * rows + row;
+				field::store_indexed(&mut dst[start .. start + height], word);
+			}
+		}
+	}
+}
+
+/// Transposes a 64 × 64-bit tile in place, where bit `c` of `tile[r]` is the
+/// bit at row `r` and column `c`.
+///
+/// Each pass exchanges the upper-right and lower-left quadrants of every
+/// diagonal block of size `2 * half`, starting with the whole tile and ending
+/// with 2 × 2 blocks.
+fn transpose64(tile: &mut [u64; TILE]) {
+	let mut half = TILE / 2;
+	let mut mask = !0u64 >> half;
+	while half != 0 {
+		let mut row = 0;
+		while row < TILE {
+			let swap = ((tile[row] >> half) ^ tile[row + half]) & mask;
+			tile[row] ^= swap << half;
+			tile[row + half] ^= swap;
+			//  Advance to the next row in the upper half of a block.
+			row = (row + half + 1) & !half;
+		}
+		half >>= 1;
+		mask ^= mask << half;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	/// Produces a deterministic, irregular matrix.
+	fn pattern<O, T>(rows: usize, cols: usize) -> BitMatrix<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let bits = dvl::xorshift(0x2545_F491_4F6C_DD1D)
+			.take(rows * cols)
+			.map(|word| word % 3 == 0)
+			.collect();
+		BitMatrix::from_bitvec(bits, rows, cols)
+	}
+
+	fn check_transpose<O, T>(rows: usize, cols: usize)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mat = pattern::<O, T>(rows, cols);
+		let tr = mat.transpose();
+		assert_eq!((tr.rows(), tr.cols()), (cols, rows));
+		for row in 0 .. rows {
+			for col in 0 .. cols {
+				assert_eq!(mat.get(row, col), tr.get(col, row));
+			}
+		}
+		assert_eq!(tr.transpose(), mat);
+	}
+
+	#[test]
+	fn transpose() {
+		for &(rows, cols) in &[(1, 1), (8, 8), (64, 64), (3, 70), (130, 65)] {
+			check_transpose::<Lsb0, u8>(rows, cols);
+			check_transpose::<Lsb0, u64>(rows, cols);
+			check_transpose::<Msb0, u16>(rows, cols);
+			check_transpose::<Msb0, u32>(rows, cols);
+			check_transpose::<LocalBits, usize>(rows, cols);
+		}
+		check_transpose::<Lsb0, usize>(0, 5);
+		check_transpose::<Msb0, usize>(5, 0);
+	}
+
+	#[test]
+	fn rows_columns() {
+		let mut mat = pattern::<Msb0, u8>(5, 13);
+		let col = mat.column(7).collect::<BitVec>();
+		let rev = mat.column(7).rev().collect::<BitVec>();
+		assert_eq!(col.len(), 5);
+		for row in 0 .. 5 {
+			assert_eq!(col[row], mat.row(row)[7]);
+			assert_eq!(rev[4 - row], col[row]);
+		}
+		assert_eq!(mat.column(7).nth(3), Some(col[3]));
+		assert_eq!(mat.column(7).len(), 5);
+
+		let (first, third) = (mat.row(1).to_bitvec(), mat.row(3).to_bitvec());
+		mat.swap_rows(3, 1);
+		assert_eq!(mat.row(1), third);
+		assert_eq!(mat.row(3), first);
+
+		mat.add_row(3, 1);
+		assert_eq!(mat.row(3), first.clone() ^ third.as_bitslice());
+		mat.add_row(1, 3);
+		assert_eq!(mat.row(1), first);
+	}
+
+	#[test]
+	fn empty_columns() {
+		let mat = BitMatrix::<Lsb0, u8>::new(0, 5);
+		assert_eq!(mat.column(3).len(), 0);
+		assert!(mat.column(4).next().is_none());
+		assert!(mat.column(4).next_back().is_none());
+	}
+
+	#[test]
+	fn multiply() {
+		let a = pattern::<Lsb0, u16>(7, 20);
+		let b = pattern::<Msb0, u8>(20, 9);
+		let c = a.multiply(&b);
+		assert_eq!((c.rows(), c.cols()), (7, 9));
+		for row in 0 .. 7 {
+			for col in 0 .. 9 {
+				let dot = (0 .. 20)
+					.filter(|&k| a.get(row, k) && b.get(k, col))
+					.count();
+				assert_eq!(c.get(row, col), dot % 2 == 1);
+			}
+		}
+
+		let id = BitMatrix::<Msb0, u8>::identity(20);
+		assert_eq!(a.multiply(&id), a);
+	}
+
+	#[test]
+	fn row_reduce() {
+		let id = BitMatrix::<Lsb0, u32>::identity(40);
+		assert_eq!(id.rank(), 40);
+		assert_eq!(BitMatrix::<Lsb0, u8>::new(4, 6).rank(), 0);
+
+		let mut mat = pattern::<Msb0, u16>(12, 30);
+		//  Make the last two rows depend on the others.
+		for row in 10 .. 12 {
+			mat.row_mut(row).set_all(false);
+			mat.add_row(row, row - 10);
+			mat.add_row(row, row - 5);
+		}
+		let rank = mat.rank();
+		assert!(rank <= 10);
+
+		let mut reduced = mat;
+		assert_eq!(reduced.row_reduce(), rank);
+		assert!(reduced.as_bitslice()[rank * 30 ..].not_any());
+		let mut last = None;
+		for row in 0 .. rank {
+			let lead = reduced.row(row).first_one().unwrap();
+			assert!(last.map_or(true, |prev| prev < lead));
+			assert_eq!(reduced.column(lead).filter(|&bit| bit).count(), 1);
+			last = Some(lead);
+		}
+		assert_eq!(reduced.transpose().rank(), rank);
+	}
+}
@@ -0,0 +1,550 @@
+/*! Rank/select acceleration over a [`BitSlice`] region.
+
+Succinct data structures lean heavily on two queries against a bit-sequence:
+
+- *rank*: how many bits set to a value precede some index, and
+- *select*: at which index does the `n`th bit set to a value live.
+
+[`BitSlice`] can answer both of these, with [`.count_ones()`] over a subslice or
+[`.iter_ones()`] followed by `.nth()`, but each query is linear in the length of
+the region being searched. This module provides the [`RankSelect`] auxiliary
+index, which spends a small amount of additional memory to answer rank queries
+in constant time and select queries in logarithmic time.
+
+# Layout
+
+The index divides its [`BitSlice`] into *blocks* of 512 bits, and groups those
+into *superblocks* of 65,536 bits. Each superblock stores the absolute number of
+`1` bits that precede it, and each block stores the number of `1` bits between
+the start of its superblock and itself. These counts are built once, from the
+element-wise population counts that [`.count_ones()`] computes through the
+[`.domain()`] of each block, and never change afterwards.
+
+A query first consults the two tables, then counts at most one block’s worth of
+bits directly out of memory. The total overhead is slightly more than three
+percent of the indexed region.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`RankSelect`]: crate::rank::RankSelect
+[`.count_ones()`]: crate::slice::BitSlice::count_ones
+[`.domain()`]: crate::slice::BitSlice::domain
+[`.iter_ones()`]: crate::slice::BitSlice::iter_ones
+!*/
+
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+};
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The number of bits covered by one entry in the block table.
+const BLOCK_BITS: usize = 512;
+
+/// The number of bits covered by one entry in the superblock table.
+///
+/// This must be a multiple of `BLOCK_BITS`, and no greater than `1 << 16`, so
+/// that the block-relative counts always fit in a `u16`.
+const SUPER_BITS: usize = 1 << 16;
+
+/// The number of blocks that make up a superblock.
+const BLOCKS_PER_SUPER: usize = SUPER_BITS / BLOCK_BITS;
+
+/** A rank/select index over a [`BitSlice`].
+
+This structure holds a handle to some [`BitSlice`] region, and a table of
+population counts computed from it. The handle can be anything that borrows as a
+`BitSlice`: `&BitSlice` for temporary indices over existing memory, or an owned
+[`BitVec`], [`BitBox`], or [`BitArray`] for indices that must carry their data
+with them. The default is to own a [`BitVec`].
+
+The index is immutable: the count tables are computed at construction and
+describe the region as it was then. Changing the bits of the region (which can
+only happen through `Cell` or atomic storage, or after [`.into_inner()`])
+invalidates the index.
+
+# Type Parameters
+
+- `O`: The ordering of bits within memory elements of the indexed region.
+- `T`: The memory element type of the indexed region.
+- `B`: The handle through which the index views the region.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::rank::RankSelect;
+
+let bits = bits![0, 1, 1, 0, 1, 0, 0, 1];
+let index = RankSelect::new(bits);
+
+assert_eq!(index.rank1(4), 2);
+assert_eq!(index.rank0(4), 2);
+assert_eq!(index.select1(2), Some(4));
+assert_eq!(index.select0(3), Some(6));
+assert!(index.select1(4).is_none());
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitBox`]: crate::boxed::BitBox
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`.into_inner()`]: Self::into_inner
+**/
+pub struct RankSelect<O = Lsb0, T = usize, B = BitVec<O, T>>
+where
+	O: BitOrder,
+	T: BitStore,
+	B: Borrow<BitSlice<O, T>>,
+{
+	/// The indexed region.
+	bits: B,
+	/// The number of `1` bits preceding each superblock.
+	supers: Vec<usize>,
+	/// The number of `1` bits between the start of each block’s superblock
+	/// and the block.
+	blocks: Vec<u16>,
+	/// The number of `1` bits in the whole region.
+	ones: usize,
+	/// The ordering of bits within a storage element `T`.
+	_ord: PhantomData<O>,
+	/// The storage type of the indexed region.
+	_typ: PhantomData<T>,
+}
+
+impl<O, T, B> RankSelect<O, T, B>
+where
+	O: BitOrder,
+	T: BitStore,
+	B: Borrow<BitSlice<O, T>>,
+{
+	/// Builds a rank/select index over a region.
+	///
+	/// This walks the region once, counting each block with
+	/// [`BitSlice::count_ones`].
+	///
+	/// # Parameters
+	///
+	/// - `bits`: A handle to the region to index.
+	///
+	/// # Returns
+	///
+	/// A rank/select index holding `bits`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1];
+	/// let borrowed = RankSelect::new(bits);
+	/// assert_eq!(borrowed.count_ones(), 2);
+	///
+	/// let owned = RankSelect::new(bitvec![Msb0, u8; 0, 1, 1, 1]);
+	/// assert_eq!(owned.count_ones(), 3);
+	/// ```
+	///
+	/// [`BitSlice::count_ones`]: crate::slice::BitSlice::count_ones
+	pub fn new(bits: B) -> Self {
+		let slice = bits.borrow();
+		let len = slice.len();
+		let mut supers = Vec::with_capacity(len / SUPER_BITS + 1);
+		let mut blocks = Vec::with_capacity(len / BLOCK_BITS + 1);
+
+		let mut ones = 0;
+		let mut relative = 0;
+		for (idx, block) in slice.chunks(BLOCK_BITS).enumerate() {
+			if idx % BLOCKS_PER_SUPER == 0 {
+				supers.push(ones);
+				relative = 0;
+			}
+			blocks.push(relative as u16);
+			let count = block.count_ones();
+			relative += count;
+			ones += count;
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+			_ord: PhantomData,
+			_typ: PhantomData,
+		}
+	}
+
+	/// Views the indexed region.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.borrow()
+	}
+
+	/// Destroys the index, returning the handle to the region it indexed.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn into_inner(self) -> B {
+		self.bits
+	}
+
+	/// Gets the number of bits in the indexed region.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.as_bitslice().len()
+	}
+
+	/// Tests if the indexed region is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.as_bitslice().is_empty()
+	}
+
+	/// Gets the number of bits in the indexed region set to `1`.
+	///
+	/// Unlike [`BitSlice::count_ones`], this is a constant-time lookup.
+	///
+	/// [`BitSlice::count_ones`]: crate::slice::BitSlice::count_ones
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Gets the number of bits in the indexed region cleared to `0`.
+	///
+	/// Unlike [`BitSlice::count_zeros`], this is a constant-time lookup.
+	///
+	/// [`BitSlice::count_zeros`]: crate::slice::BitSlice::count_zeros
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.len() - self.ones
+	}
+
+	/// Counts the bits set to `1` before an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The end of the counted range, exclusive. This may be equal to
+	///   `self.len()`, in which case the whole region is counted.
+	///
+	/// # Returns
+	///
+	/// The number of bits set to `1` in `self.as_bitslice()[.. index]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 1, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank1(0), 0);
+	/// assert_eq!(index.rank1(3), 2);
+	/// assert_eq!(index.rank1(4), 3);
+	/// ```
+	pub fn rank1(&self, index: usize) -> usize {
+		let bits = self.as_bitslice();
+		bits.assert_in_bounds(index, 0 ..= bits.len());
+		let block = index / BLOCK_BITS;
+		//  `index` may sit exactly at the end of the last block.
+		if block == self.blocks.len() {
+			return self.ones;
+		}
+		self.ones_before(block)
+			+ unsafe { bits.get_unchecked(block * BLOCK_BITS .. index) }
+				.count_ones()
+	}
+
+	/// Counts the bits cleared to `0` before an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The end of the counted range, exclusive. This may be equal to
+	///   `self.len()`, in which case the whole region is counted.
+	///
+	/// # Returns
+	///
+	/// The number of bits cleared to `0` in `self.as_bitslice()[.. index]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 1, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank0(2), 0);
+	/// assert_eq!(index.rank0(4), 1);
+	/// ```
+	#[inline]
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of a bit set to `1` by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of `1` bits that precede the sought bit. `0`
+	///   selects the first bit set to `1`.
+	///
+	/// # Returns
+	///
+	/// The index `i` such that `self.as_bitslice()[i]` is `1` and
+	/// `self.rank1(i) == rank`, if the region has more than `rank` bits set to
+	/// `1`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 1, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select1(0), Some(1));
+	/// assert_eq!(index.select1(1), Some(3));
+	/// assert!(index.select1(2).is_none());
+	/// ```
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		if rank >= self.ones {
+			return None;
+		}
+		//  Find the last block that starts with no more than `rank` ones.
+		let block = self.last_block_where(|blk| self.ones_before(blk) <= rank);
+		let start = block * BLOCK_BITS;
+		let remaining = rank - self.ones_before(block);
+		self.block(block)
+			.iter_ones()
+			.nth(remaining)
+			.map(|idx| idx + start)
+	}
+
+	/// Finds the index of a bit cleared to `0` by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of `0` bits that precede the sought bit. `0`
+	///   selects the first bit cleared to `0`.
+	///
+	/// # Returns
+	///
+	/// The index `i` such that `self.as_bitslice()[i]` is `0` and
+	/// `self.rank0(i) == rank`, if the region has more than `rank` bits
+	/// cleared to `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 1, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select0(0), Some(0));
+	/// assert_eq!(index.select0(1), Some(2));
+	/// assert!(index.select0(2).is_none());
+	/// ```
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_zeros() {
+			return None;
+		}
+		let zeros_before = |blk: usize| blk * BLOCK_BITS - self.ones_before(blk);
+		let block = self.last_block_where(|blk| zeros_before(blk) <= rank);
+		let start = block * BLOCK_BITS;
+		let remaining = rank - zeros_before(block);
+		self.block(block)
+			.iter_zeros()
+			.nth(remaining)
+			.map(|idx| idx + start)
+	}
+
+	/// Looks up the number of `1` bits preceding a block.
+	#[inline]
+	fn ones_before(&self, block: usize) -> usize {
+		self.supers[block / BLOCKS_PER_SUPER] + self.blocks[block] as usize
+	}
+
+	/// Views the bits of one block.
+	fn block(&self, block: usize) -> &BitSlice<O, T> {
+		let bits = self.as_bitslice();
+		let start = block * BLOCK_BITS;
+		let end = core::cmp::min(start + BLOCK_BITS, bits.len());
+		unsafe { bits.get_unchecked(start .. end) }
+	}
+
+	/// Binary-searches the block table for the last block satisfying a
+	/// predicate that holds for some prefix of the table.
+	///
+	/// The predicate must hold for block `0`.
+	fn last_block_where<F>(&self, pred: F) -> usize
+	where F: Fn(usize) -> bool {
+		let (mut lo, mut hi) = (0, self.blocks.len());
+		//  Invariant: `pred(lo)` holds, and `pred(hi)` does not (or `hi` is the
+		//  end of the table).
+		while hi - lo > 1 {
+			let mid = lo + (hi - lo) / 2;
+			if pred(mid) {
+				lo = mid;
+			}
+			else {
+				hi = mid;
+			}
+		}
+		lo
+	}
+}
+
+impl<O, T, B> Clone for RankSelect<O, T, B>
+where
+	O: BitOrder,
+	T: BitStore,
+	B: Borrow<BitSlice<O, T>> + Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			supers: self.supers.clone(),
+			blocks: self.blocks.clone(),
+			ones: self.ones,
+			_ord: PhantomData,
+			_typ: PhantomData,
+		}
+	}
+}
+
+impl<O, T, B> Debug for RankSelect<O, T, B>
+where
+	O: BitOrder,
+	T: BitStore,
+	B: Borrow<BitSlice<O, T>>,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RankSelect")
+			.field("bits", &self.as_bitslice())
+			.field("ones", &self.ones)
+			.finish()
+	}
+}
+
+impl<O, T> From<BitVec<O, T>> for RankSelect<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	fn from(bits: BitVec<O, T>) -> Self {
+		Self::new(bits)
+	}
+}
+
+impl<'a, O, T> From<&'a BitSlice<O, T>> for RankSelect<O, T, &'a BitSlice<O, T>>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	fn from(bits: &'a BitSlice<O, T>) -> Self {
+		Self::new(bits)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		devel as dvl,
+		prelude::*,
+	};
+
+	/// Produces a deterministic, irregular bit pattern.
+	fn pattern(len: usize) -> BitVec<Msb0, u16> {
+		dvl::xorshift(0x2545_F491_4F6C_DD1D)
+			.take(len)
+			.map(|word| word % 3 == 0)
+			.collect()
+	}
+
+	#[test]
+	fn rank() {
+		let bv = pattern(SUPER_BITS * 2 + 1000);
+		let index = RankSelect::new(&bv[3 ..]);
+		let bits = &bv[3 ..];
+
+		assert_eq!(index.count_ones(), bits.count_ones());
+		assert_eq!(index.count_zeros(), bits.count_zeros());
+		for idx in (0 ..= bits.len()).step_by(97).chain(Some(bits.len())) {
+			assert_eq!(index.rank1(idx), bits[.. idx].count_ones());
+			assert_eq!(index.rank0(idx), bits[.. idx].count_zeros());
+		}
+	}
+
+	#[test]
+	fn select() {
+		let bits = pattern(SUPER_BITS + 3000);
+		let index = RankSelect::new(bits.clone());
+
+		for (rank, idx) in bits.iter_ones().enumerate().step_by(31) {
+			assert_eq!(index.select1(rank), Some(idx));
+			assert_eq!(index.rank1(idx), rank);
+		}
+		for (rank, idx) in bits.iter_zeros().enumerate().step_by(31) {
+			assert_eq!(index.select0(rank), Some(idx));
+			assert_eq!(index.rank0(idx), rank);
+		}
+
+		assert_eq!(index.select1(index.count_ones() - 1), bits.last_one());
+		assert_eq!(index.select0(index.count_zeros() - 1), bits.last_zero());
+		assert!(index.select1(index.count_ones()).is_none());
+		assert!(index.select0(index.count_zeros()).is_none());
+		assert_eq!(index.into_inner(), bits);
+	}
+
+	#[test]
+	fn empty() {
+		let index = RankSelect::new(BitSlice::<LocalBits, usize>::empty());
+		assert!(index.is_empty());
+		assert_eq!(index.rank1(0), 0);
+		assert!(index.select1(0).is_none());
+		assert!(index.select0(0).is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn rank_out_of_bounds() {
+		RankSelect::new(bits![0, 1]).rank1(3);
+	}
+}
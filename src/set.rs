@@ -0,0 +1,604 @@
+/*! A growable set of indices, stored as a [`BitVec`].
+
+A [`BitVec`] is a sequence of bits, and its API follows that of [`Vec<bool>`].
+Many programs use a bit-vector to store a dense set of small integers instead:
+the integer `n` is a member of the set if the bit at index `n` is `1`. This
+module provides [`BitSet`], which wraps a [`BitVec`] with the API of a set
+collection, similar to the standard library’s [`BTreeSet<usize>`].
+
+For sets of values spread across the whole `u32` range, [`RoaringBitSet`]
+divides the values into fixed-size chunks and stores each one as either a sorted
+list or a bitmap, depending on how full it is.
+
+[`BTreeSet<usize>`]: alloc::collections::BTreeSet
+[`BitSet`]: crate::set::BitSet
+[`RoaringBitSet`]: crate::set::RoaringBitSet
+[`BitVec`]: crate::vec::BitVec
+[`Vec<bool>`]: alloc::vec::Vec
+!*/
+
+#![cfg(feature = "alloc")]
+
+mod roaring;
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+};
+
+pub use self::roaring::{
+	RoaringBitSet,
+	RoaringIter,
+};
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitOp,
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+/** A set of `usize` values, stored as a bit-vector.
+
+Each value in the set is stored as a `1` bit at the index of that value in an
+underlying [`BitVec`]. Inserting a value past the end of the bit-vector grows it
+to fit; removing values never shrinks it, but [`.shrink_to_fit()`] can be used
+to discard trailing `0` bits.
+
+Because the set is dense, its memory use is proportional to its largest member,
+not to the number of members. It is best suited for sets of small, clustered
+values.
+
+The set is flat: there is no summary level above the bit-vector, so
+[`.len()`] and iteration scan every memory element up to the largest member.
+
+# Type Parameters
+
+- `O`: The ordering of bits within memory elements of the underlying
+  bit-vector.
+- `T`: The memory element type of the underlying bit-vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::set::BitSet;
+
+let mut set = BitSet::<Lsb0, u8>::new();
+assert!(set.insert(3));
+assert!(set.insert(10));
+assert!(!set.insert(3));
+
+assert!(set.contains(10));
+assert!(!set.contains(4));
+assert_eq!(set.len(), 2);
+assert_eq!(set.iter().collect::<Vec<_>>(), [3, 10]);
+assert_eq!(format!("{:?}", set), "{3, 10}");
+```
+
+[`BitVec`]: crate::vec::BitVec
+[`.len()`]: Self::len
+[`.shrink_to_fit()`]: Self::shrink_to_fit
+**/
+#[derive(Clone, Default)]
+pub struct BitSet<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The membership bits, indexed by value.
+	bits: BitVec<O, T>,
+}
+
+impl<O, T> BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs a new, empty, set.
+	///
+	/// This does not allocate until a value is inserted.
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			bits: BitVec::new(),
+		}
+	}
+
+	/// Constructs a new, empty, set that can hold the values `0 .. capacity`
+	/// without reällocating.
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::with_capacity(capacity),
+		}
+	}
+
+	/// Constructs a set whose members are the indices of all `1` bits in a
+	/// bit-vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::from_bitvec(bitvec![0, 1, 1, 0]);
+	/// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2]);
+	/// ```
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn from_bitvec(bits: BitVec<O, T>) -> Self {
+		Self { bits }
+	}
+
+	/// Unwraps the set into its underlying bit-vector.
+	///
+	/// The bit-vector may have any number of trailing `0` bits.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Views the set as its underlying bit-slice.
+	///
+	/// The bit-slice may have any number of trailing `0` bits.
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Gets the number of values the set can hold without reällocating.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity()
+	}
+
+	/// Counts the members of the set.
+	///
+	/// This is not a stored value: it counts the `1` bits of the underlying
+	/// bit-vector, a memory element at a time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = [1, 5, 9].iter().copied().collect::<BitSet>();
+	/// assert_eq!(set.len(), 3);
+	/// ```
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Tests if a value is a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = [1, 5].iter().copied().collect::<BitSet>();
+	/// assert!(set.contains(5));
+	/// assert!(!set.contains(2));
+	/// assert!(!set.contains(1000));
+	/// ```
+	#[inline]
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).map_or(false, |bit| *bit)
+	}
+
+	/// Adds a value to the set, growing the set if necessary.
+	///
+	/// # Returns
+	///
+	/// `true` if the value was not already a member of the set.
+	///
+	/// # Panics
+	///
+	/// This panics if `value` is not less than [`BitSlice::MAX_BITS`], as the
+	/// set cannot grow to hold it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<bitvec::order::Msb0, u16>::new();
+	/// assert!(set.insert(100));
+	/// assert!(!set.insert(100));
+	/// assert!(set.contains(100));
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	pub fn insert(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			let len = value
+				.checked_add(1)
+				.filter(|&len| len <= BitSlice::<O, T>::MAX_BITS)
+				.expect("BitSet values must be less than `BitSlice::MAX_BITS`");
+			self.bits.resize(len, false);
+		}
+		let prev = self.bits[value];
+		self.bits.set(value, true);
+		!prev
+	}
+
+	/// Removes a value from the set.
+	///
+	/// This never shrinks the set.
+	///
+	/// # Returns
+	///
+	/// `true` if the value was a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = [4].iter().copied().collect::<BitSet>();
+	/// assert!(set.remove(4));
+	/// assert!(!set.remove(4));
+	/// assert!(!set.remove(400));
+	/// ```
+	pub fn remove(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			return false;
+		}
+		let prev = self.bits[value];
+		self.bits.set(value, false);
+		prev
+	}
+
+	/// Removes all values from the set.
+	///
+	/// This does not release the allocation.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Discards trailing non-members from the underlying bit-vector, and
+	/// releases any unused capacity.
+	pub fn shrink_to_fit(&mut self) {
+		let len = self.bits.last_one().map_or(0, |idx| idx + 1);
+		self.bits.truncate(len);
+		self.bits.shrink_to_fit();
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	///
+	/// This is [`BitSlice::iter_ones`] on the underlying bit-vector.
+	///
+	/// [`BitSlice::iter_ones`]: crate::slice::BitSlice::iter_ones
+	#[inline]
+	pub fn iter(&self) -> IterOnes<O, T> {
+		self.bits.iter_ones()
+	}
+
+	/// Adds all members of `other` to `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2].iter().copied().collect::<BitSet>();
+	/// let b = [2, 30].iter().copied().collect::<BitSet>();
+	/// a.union_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 2, 30]);
+	/// ```
+	pub fn union_with<O2, T2>(&mut self, other: &BitSet<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.grow_to(other.bits.len());
+		self.bits.bitop_assign(other.as_bitslice(), BitOp::Or);
+	}
+
+	/// Removes all members of `self` that are not in `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2, 30].iter().copied().collect::<BitSet>();
+	/// let b = [2, 3].iter().copied().collect::<BitSet>();
+	/// a.intersect_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [2]);
+	/// ```
+	pub fn intersect_with<O2, T2>(&mut self, other: &BitSet<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.bits.bitop_assign(other.as_bitslice(), BitOp::And);
+	}
+
+	/// Removes all members of `other` from `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2, 30].iter().copied().collect::<BitSet>();
+	/// let b = [2, 3].iter().copied().collect::<BitSet>();
+	/// a.difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 30]);
+	/// ```
+	pub fn difference_with<O2, T2>(&mut self, other: &BitSet<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.bits.bitop_assign(other.as_bitslice(), BitOp::AndNot);
+	}
+
+	/// Keeps only the values that are members of exactly one of `self` and
+	/// `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2, 30].iter().copied().collect::<BitSet>();
+	/// let b = [2, 3].iter().copied().collect::<BitSet>();
+	/// a.symmetric_difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 3, 30]);
+	/// ```
+	pub fn symmetric_difference_with<O2, T2>(&mut self, other: &BitSet<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.grow_to(other.bits.len());
+		self.bits.bitop_assign(other.as_bitslice(), BitOp::Xor);
+	}
+
+	/// Tests if every member of `self` is a member of `other`.
+	#[inline]
+	pub fn is_subset<O2, T2>(&self, other: &BitSet<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.bits.is_subset_of(other.as_bitslice())
+	}
+
+	/// Tests if every member of `other` is a member of `self`.
+	#[inline]
+	pub fn is_superset<O2, T2>(&self, other: &BitSet<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.bits.is_superset_of(other.as_bitslice())
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	#[inline]
+	pub fn is_disjoint<O2, T2>(&self, other: &BitSet<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.bits.is_disjoint(other.as_bitslice())
+	}
+
+	/// Ensures that the underlying bit-vector is at least `len` bits long.
+	fn grow_to(&mut self, len: usize) {
+		let len = cmp::max(len, self.bits.len());
+		self.bits.resize(len, false);
+	}
+}
+
+impl<O, T> Debug for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<O, T> Eq for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/// Sets are equal when they have the same members, regardless of the lengths of
+/// their underlying bit-vectors.
+impl<O1, O2, T1, T2> PartialEq<BitSet<O2, T2>> for BitSet<O1, T1>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	T1: BitStore,
+	T2: BitStore,
+{
+	fn eq(&self, other: &BitSet<O2, T2>) -> bool {
+		self.bits.hamming_distance(other.as_bitslice()) == 0
+	}
+}
+
+impl<O, T> Hash for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		for value in self.iter() {
+			value.hash(state);
+		}
+	}
+}
+
+impl<O, T> Extend<usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+impl<O, T> FromIterator<usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl<O, T> From<BitVec<O, T>> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	fn from(bits: BitVec<O, T>) -> Self {
+		Self::from_bitvec(bits)
+	}
+}
+
+impl<'a, O, T> IntoIterator for &'a BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type IntoIter = IterOnes<'a, O, T>;
+	type Item = usize;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(not(feature = "std"))]
+	use alloc::{
+		format,
+		vec,
+		vec::Vec,
+	};
+
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn membership() {
+		let mut set = BitSet::<Msb0, u8>::new();
+		assert!(set.is_empty());
+		assert!(!set.contains(0));
+
+		assert!(set.insert(17));
+		assert!(set.insert(0));
+		assert!(!set.insert(17));
+		assert_eq!(set.len(), 2);
+		assert!(set.as_bitslice().len() >= 18);
+
+		assert!(set.remove(17));
+		assert!(!set.remove(17));
+		assert!(!set.remove(1000));
+		assert_eq!(set.iter().collect::<Vec<_>>(), [0]);
+
+		set.shrink_to_fit();
+		assert_eq!(set.as_bitslice(), bits![1]);
+		set.clear();
+		assert!(set.is_empty());
+	}
+
+	#[test]
+	#[should_panic]
+	fn insert_too_large() {
+		BitSet::<Lsb0, u8>::new().insert(usize::MAX);
+	}
+
+	#[test]
+	fn algebra() {
+		let evens = (0 .. 100).step_by(2).collect::<BitSet>();
+		let triples = (0 .. 150).step_by(3).collect::<BitSet<Msb0, u16>>();
+		let sixes = (0 .. 100).step_by(6).collect::<BitSet<Lsb0, u8>>();
+
+		let mut set = evens.clone();
+		set.intersect_with(&triples);
+		assert_eq!(set, sixes);
+		assert!(set.is_subset(&evens) && set.is_subset(&triples));
+		assert!(evens.is_superset(&set));
+
+		let mut set = evens.clone();
+		set.union_with(&triples);
+		assert!(set.iter().all(|n| n % 2 == 0 || n % 3 == 0));
+		assert_eq!(set.len(), 50 + 50 - 17);
+		assert!(set.contains(147));
+
+		let mut set = evens.clone();
+		set.difference_with(&triples);
+		assert!(set.is_disjoint(&triples));
+		assert_eq!(set.len(), 50 - 17);
+
+		let mut set = evens;
+		set.symmetric_difference_with(&triples);
+		assert!(set.is_disjoint(&sixes));
+		assert_eq!(set.len(), 50 + 50 - 2 * 17);
+	}
+
+	#[test]
+	fn traits() {
+		let mut set = BitSet::<Lsb0, u32>::from(bitvec![Lsb0, u32; 0, 1, 0]);
+		set.extend(vec![8, 3]);
+		assert_eq!(format!("{:?}", set), "{1, 3, 8}");
+		assert_eq!((&set).into_iter().count(), 3);
+
+		let mut other = [8, 3, 1].iter().copied().collect::<BitSet<Msb0, u8>>();
+		assert_eq!(set, other);
+		other.insert(200);
+		other.remove(200);
+		assert_eq!(set, other);
+		assert_eq!(set.into_bitvec().count_ones(), 3);
+	}
+}
@@ -0,0 +1,989 @@
+/*! A compressed set of `u32` values, in the style of Roaring bitmaps.
+
+The 32-bit value space is split into 2<sup>16</sup> chunks, keyed by the high
+half of each value. Each non-empty chunk is stored in a container chosen by its
+population: a sparse chunk is a sorted array of the low halves of its members,
+and a dense chunk is a 2<sup>16</sup>-bit map built from [`BitArray`]s. A chunk
+switches representation whenever its population crosses 4096 members, so no
+container is ever larger than eight kibibytes.
+
+[`BitArray`]: crate::array::BitArray
+!*/
+
+use alloc::{
+	boxed::Box,
+	vec,
+	vec::Vec,
+};
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		Enumerate,
+		FromIterator,
+		FusedIterator,
+	},
+	mem,
+	slice,
+};
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitOp,
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The number of bits in a chunk of the value space.
+const CHUNK_BITS: usize = 1 << 16;
+
+/// The largest population stored as a sorted array. Above this, an array would
+/// be larger than the bitmap of the same chunk.
+const ARRAY_MAX: usize = 4096;
+
+/// The number of bits in one dense block.
+const BLOCK_BITS: usize = 2048;
+
+/// The number of dense blocks in a bitmap container.
+const BLOCKS: usize = CHUNK_BITS / BLOCK_BITS;
+
+/** One segment of a bitmap container.
+
+A whole chunk would need an array of 1024 `usize`s, which is wider than the
+arrays that [`BitView`] is implemented for, so bitmap containers are divided
+into blocks of 64 `u32`s instead.
+
+[`BitView`]: crate::view::BitView
+**/
+type Block = crate::BitArr!(for BLOCK_BITS, in Lsb0, u32);
+
+/** A set of `u32` values, stored as a sorted sequence of compressed chunks.
+
+Values are grouped by their high 16 bits. Each group holds its low 16 bits in a
+sorted `Vec<u16>` while it has at most 4096 members, and in a 2<sup>16</sup>-bit
+map once it grows beyond that. Memory use is therefore proportional to the
+number of members in sparse regions, and bounded at one bit per value in dense
+ones.
+
+Unlike [`BitSet`], which needs one bit for every value below its largest member,
+this set can hold values scattered across the whole `u32` range.
+
+# Examples
+
+```rust
+use bitvec::set::RoaringBitSet;
+
+let mut set = RoaringBitSet::new();
+assert!(set.insert(7));
+assert!(set.insert(4_000_000_000));
+assert!(!set.insert(7));
+
+assert!(set.contains(4_000_000_000));
+assert_eq!(set.len(), 2);
+assert_eq!(set.iter().collect::<Vec<_>>(), [7, 4_000_000_000]);
+assert_eq!(format!("{:?}", set), "{7, 4000000000}");
+```
+
+[`BitSet`]: crate::set::BitSet
+**/
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct RoaringBitSet {
+	/// The high halves of the members, in ascending order.
+	keys: Vec<u16>,
+	/// The low halves of the members, one container for each key. Containers
+	/// are never empty.
+	containers: Vec<Container>,
+}
+
+impl RoaringBitSet {
+	/// Constructs a new, empty, set.
+	///
+	/// This does not allocate until a value is inserted.
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Counts the members of the set.
+	///
+	/// This is a `u64`, as the set can hold all 2<sup>32</sup> `u32` values.
+	#[inline]
+	pub fn len(&self) -> u64 {
+		self.containers.iter().map(|c| c.len() as u64).sum()
+	}
+
+	/// Tests if the set has no members.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.keys.is_empty()
+	}
+
+	/// Tests if a value is a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let set = [1, 70_000].iter().copied().collect::<RoaringBitSet>();
+	/// assert!(set.contains(70_000));
+	/// assert!(!set.contains(4_465));
+	/// ```
+	#[inline]
+	pub fn contains(&self, value: u32) -> bool {
+		let (high, low) = split(value);
+		self.find(high)
+			.map_or(false, |idx| self.containers[idx].contains(low))
+	}
+
+	/// Adds a value to the set.
+	///
+	/// # Returns
+	///
+	/// `true` if the value was not already a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let mut set = RoaringBitSet::new();
+	/// assert!(set.insert(100));
+	/// assert!(!set.insert(100));
+	/// assert!(set.contains(100));
+	/// ```
+	pub fn insert(&mut self, value: u32) -> bool {
+		let (high, low) = split(value);
+		match self.keys.binary_search(&high) {
+			Ok(idx) => self.containers[idx].insert(low),
+			Err(idx) => {
+				self.keys.insert(idx, high);
+				self.containers.insert(idx, Container::Array(vec![low]));
+				true
+			},
+		}
+	}
+
+	/// Removes a value from the set.
+	///
+	/// # Returns
+	///
+	/// `true` if the value was a member of the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let mut set = [4].iter().copied().collect::<RoaringBitSet>();
+	/// assert!(set.remove(4));
+	/// assert!(!set.remove(4));
+	/// assert!(set.is_empty());
+	/// ```
+	pub fn remove(&mut self, value: u32) -> bool {
+		let (high, low) = split(value);
+		let idx = match self.find(high) {
+			Some(idx) => idx,
+			None => return false,
+		};
+		let out = self.containers[idx].remove(low);
+		if self.containers[idx].len() == 0 {
+			self.keys.remove(idx);
+			self.containers.remove(idx);
+		}
+		out
+	}
+
+	/// Removes all values from the set.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.keys.clear();
+		self.containers.clear();
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	#[inline]
+	pub fn iter(&self) -> RoaringIter {
+		RoaringIter {
+			keys: self.keys.iter(),
+			containers: self.containers.iter(),
+			front: None,
+		}
+	}
+
+	/// Copies the set into a bit-vector, with a `1` bit at the index of each
+	/// member.
+	///
+	/// The bit-vector ends at the largest member, and is empty when the set is.
+	///
+	/// # Panics
+	///
+	/// This panics if the largest member is too large to be an index in a
+	/// bit-vector on the target.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let set = [1, 4].iter().copied().collect::<RoaringBitSet>();
+	/// assert_eq!(set.to_bitvec::<Msb0, u8>(), bits![0, 1, 0, 0, 1]);
+	/// ```
+	pub fn to_bitvec<O, T>(&self) -> BitVec<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = self.last().map_or(0, |max| {
+			(max as usize)
+				.checked_add(1)
+				.expect("Set member is too large for a bit-vector")
+		});
+		let mut out = BitVec::repeat(false, len);
+		for (&high, container) in self.keys.iter().zip(&self.containers) {
+			let base = (high as usize) << 16;
+			match container {
+				Container::Array(values) => {
+					for &low in values {
+						out.set(base + low as usize, true);
+					}
+				},
+				Container::Bitmap(blocks, _) => {
+					for (idx, block) in blocks.iter().enumerate() {
+						let start = base + idx * BLOCK_BITS;
+						if start >= len {
+							break;
+						}
+						let end = cmp::min(start + BLOCK_BITS, len);
+						out[start .. end]
+							.clone_from_bitslice(&block[.. end - start]);
+					}
+				},
+			}
+		}
+		out
+	}
+
+	/// Adds all members of `other` to `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let mut a = [1, 2].iter().copied().collect::<RoaringBitSet>();
+	/// let b = [2, 300_000].iter().copied().collect::<RoaringBitSet>();
+	/// a.union_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 2, 300_000]);
+	/// ```
+	#[inline]
+	pub fn union_with(&mut self, other: &Self) {
+		self.merge(other, BitOp::Or);
+	}
+
+	/// Removes all members of `self` that are not in `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let mut a = [1, 2, 300_000].iter().copied().collect::<RoaringBitSet>();
+	/// let b = [2, 3].iter().copied().collect::<RoaringBitSet>();
+	/// a.intersect_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [2]);
+	/// ```
+	#[inline]
+	pub fn intersect_with(&mut self, other: &Self) {
+		self.merge(other, BitOp::And);
+	}
+
+	/// Removes all members of `other` from `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let mut a = [1, 2, 300_000].iter().copied().collect::<RoaringBitSet>();
+	/// let b = [2, 3].iter().copied().collect::<RoaringBitSet>();
+	/// a.difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 300_000]);
+	/// ```
+	#[inline]
+	pub fn difference_with(&mut self, other: &Self) {
+		self.merge(other, BitOp::AndNot);
+	}
+
+	/// Keeps only the values that are members of exactly one of `self` and
+	/// `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::RoaringBitSet;
+	///
+	/// let mut a = [1, 2, 300_000].iter().copied().collect::<RoaringBitSet>();
+	/// let b = [2, 3].iter().copied().collect::<RoaringBitSet>();
+	/// a.symmetric_difference_with(&b);
+	/// assert_eq!(a.iter().collect::<Vec<_>>(), [1, 3, 300_000]);
+	/// ```
+	#[inline]
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		self.merge(other, BitOp::Xor);
+	}
+
+	/// Tests if every member of `self` is a member of `other`.
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.keys
+			.iter()
+			.zip(&self.containers)
+			.all(|(&high, container)| {
+				other.find(high).map_or(false, |idx| {
+					container.is_subset(&other.containers[idx])
+				})
+			})
+	}
+
+	/// Tests if every member of `other` is a member of `self`.
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.keys
+			.iter()
+			.zip(&self.containers)
+			.all(|(&high, container)| {
+				other.find(high).map_or(true, |idx| {
+					container.is_disjoint(&other.containers[idx])
+				})
+			})
+	}
+
+	/// Finds the largest member of the set.
+	fn last(&self) -> Option<u32> {
+		let high = *self.keys.last()?;
+		let low = self.containers.last()?.last();
+		Some(join(high, low))
+	}
+
+	/// Finds the container for a high half, if it has any members.
+	#[inline]
+	fn find(&self, high: u16) -> Option<usize> {
+		self.keys.binary_search(&high).ok()
+	}
+
+	/// Combines `other` into `self`, one chunk at a time.
+	///
+	/// Chunks present in only one operand are kept or dropped according to
+	/// `op`, without being examined.
+	fn merge(&mut self, other: &Self, op: BitOp) {
+		let keep_left = op != BitOp::And;
+		let keep_right = op == BitOp::Or || op == BitOp::Xor;
+
+		let keys = mem::take(&mut self.keys);
+		let containers = mem::take(&mut self.containers);
+		let mut left = keys.into_iter().zip(containers).peekable();
+		let mut right = other.keys.iter().zip(&other.containers).peekable();
+
+		loop {
+			let order = match (left.peek(), right.peek()) {
+				(None, None) => break,
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(Some(&(l, _)), Some(&(&r, _))) => l.cmp(&r),
+			};
+			match order {
+				Ordering::Less => {
+					let (high, container) = left.next().unwrap();
+					if keep_left {
+						self.push(high, Some(container));
+					}
+				},
+				Ordering::Greater => {
+					let (&high, container) = right.next().unwrap();
+					if keep_right {
+						self.push(high, Some(container.clone()));
+					}
+				},
+				Ordering::Equal => {
+					let (high, a) = left.next().unwrap();
+					let (_, b) = right.next().unwrap();
+					self.push(high, a.combine(b, op));
+				},
+			}
+		}
+	}
+
+	/// Appends a chunk to the set, if it is not empty.
+	#[inline]
+	fn push(&mut self, high: u16, container: Option<Container>) {
+		if let Some(container) = container {
+			self.keys.push(high);
+			self.containers.push(container);
+		}
+	}
+}
+
+impl Debug for RoaringBitSet {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl Extend<u32> for RoaringBitSet {
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u32> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+impl FromIterator<u32> for RoaringBitSet {
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = u32> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+/** Collects the indices of all `1` bits in a bit-slice.
+
+# Panics
+
+This panics if the bit-slice is longer than 2<sup>32</sup> bits, as its
+indices would not fit in `u32`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::set::RoaringBitSet;
+
+let set = RoaringBitSet::from(bits![Msb0, u8; 0, 1, 1, 0]);
+assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2]);
+```
+**/
+impl<O, T> From<&BitSlice<O, T>> for RoaringBitSet
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: &BitSlice<O, T>) -> Self {
+		assert!(
+			bits.len() as u64 <= 1 << 32,
+			"A bit-slice of {} bits is too long for a set of `u32`",
+			bits.len(),
+		);
+		let mut out = Self::new();
+		for (high, chunk) in bits.chunks(CHUNK_BITS).enumerate() {
+			let container = match chunk.count_ones() {
+				0 => continue,
+				n if n <= ARRAY_MAX => Container::Array(
+					chunk.iter_ones().map(|idx| idx as u16).collect(),
+				),
+				n => {
+					let mut blocks = Container::empty_blocks();
+					for (block, src) in
+						blocks.iter_mut().zip(chunk.chunks(BLOCK_BITS))
+					{
+						block[.. src.len()].clone_from_bitslice(src);
+					}
+					Container::Bitmap(blocks, n)
+				},
+			};
+			out.push(high as u16, Some(container));
+		}
+		out
+	}
+}
+
+impl<'a> IntoIterator for &'a RoaringBitSet {
+	type IntoIter = RoaringIter<'a>;
+	type Item = u32;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** Iterates over the members of a [`RoaringBitSet`], in ascending order.
+
+This is created by [`RoaringBitSet::iter`].
+
+[`RoaringBitSet`]: crate::set::RoaringBitSet
+[`RoaringBitSet::iter`]: crate::set::RoaringBitSet::iter
+**/
+#[derive(Clone, Debug)]
+pub struct RoaringIter<'a> {
+	/// The high halves of the chunks not yet begun.
+	keys: slice::Iter<'a, u16>,
+	/// The containers of the chunks not yet begun.
+	containers: slice::Iter<'a, Container>,
+	/// The high half and remaining members of the current chunk.
+	front: Option<(u16, ContainerIter<'a>)>,
+}
+
+impl Iterator for RoaringIter<'_> {
+	type Item = u32;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((high, ref mut rest)) = self.front {
+				if let Some(low) = rest.next() {
+					return Some(join(high, low));
+				}
+			}
+			let high = *self.keys.next()?;
+			let container = self.containers.next()?;
+			self.front = Some((high, container.iter()));
+		}
+	}
+}
+
+impl FusedIterator for RoaringIter<'_> {
+}
+
+/// A chunk of the value space, holding the low halves of its members.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Container {
+	/// The sorted members of a chunk with at most `ARRAY_MAX` of them.
+	Array(Vec<u16>),
+	/// The membership bits of a chunk with more than `ARRAY_MAX` members, and
+	/// the number of members.
+	Bitmap(Box<[Block]>, usize),
+}
+
+impl Container {
+	/// Allocates the blocks of an empty bitmap container.
+	fn empty_blocks() -> Box<[Block]> {
+		vec![Block::zeroed(); BLOCKS].into_boxed_slice()
+	}
+
+	/// Counts the members of the chunk.
+	#[inline]
+	fn len(&self) -> usize {
+		match self {
+			Self::Array(values) => values.len(),
+			Self::Bitmap(_, count) => *count,
+		}
+	}
+
+	/// Finds the largest member of a non-empty chunk.
+	fn last(&self) -> u16 {
+		match self {
+			Self::Array(values) => *values.last().unwrap(),
+			Self::Bitmap(blocks, _) => blocks
+				.iter()
+				.enumerate()
+				.rev()
+				.find_map(|(idx, block)| {
+					block.last_one().map(|bit| idx * BLOCK_BITS + bit)
+				})
+				.unwrap() as u16,
+		}
+	}
+
+	/// Tests if a low half is a member of the chunk.
+	#[inline]
+	fn contains(&self, low: u16) -> bool {
+		match self {
+			Self::Array(values) => values.binary_search(&low).is_ok(),
+			Self::Bitmap(blocks, _) => {
+				let (block, bit) = locate(low);
+				blocks[block][bit]
+			},
+		}
+	}
+
+	/// Adds a low half to the chunk, converting it to a bitmap if it becomes
+	/// too full for an array.
+	fn insert(&mut self, low: u16) -> bool {
+		match self {
+			Self::Array(values) => match values.binary_search(&low) {
+				Ok(_) => false,
+				Err(idx) => {
+					values.insert(idx, low);
+					if values.len() > ARRAY_MAX {
+						*self = Self::bitmap_from(values);
+					}
+					true
+				},
+			},
+			Self::Bitmap(blocks, count) => {
+				let (block, bit) = locate(low);
+				let prev = blocks[block][bit];
+				blocks[block].set(bit, true);
+				*count += !prev as usize;
+				!prev
+			},
+		}
+	}
+
+	/// Removes a low half from the chunk, converting it to an array if it
+	/// becomes sparse enough.
+	fn remove(&mut self, low: u16) -> bool {
+		match self {
+			Self::Array(values) => match values.binary_search(&low) {
+				Ok(idx) => {
+					values.remove(idx);
+					true
+				},
+				Err(_) => false,
+			},
+			Self::Bitmap(blocks, count) => {
+				let (block, bit) = locate(low);
+				let prev = blocks[block][bit];
+				blocks[block].set(bit, false);
+				*count -= prev as usize;
+				if *count <= ARRAY_MAX {
+					*self = Self::Array(Self::iter_blocks(blocks).collect());
+				}
+				prev
+			},
+		}
+	}
+
+	/// Iterates over the members of the chunk.
+	fn iter(&self) -> ContainerIter {
+		match self {
+			Self::Array(values) => ContainerIter::Array(values.iter()),
+			Self::Bitmap(blocks, _) => Self::iter_blocks(blocks),
+		}
+	}
+
+	/// Iterates over the members of a bitmap container.
+	fn iter_blocks(blocks: &[Block]) -> ContainerIter {
+		ContainerIter::Bitmap {
+			blocks: blocks.iter().enumerate(),
+			front: None,
+		}
+	}
+
+	/// Combines two containers for the same chunk.
+	///
+	/// # Returns
+	///
+	/// The combined container, or `None` if it has no members.
+	fn combine(self, other: &Self, op: BitOp) -> Option<Self> {
+		let mut blocks = match self {
+			Self::Array(mut values) => match other {
+				Self::Array(other) => {
+					return Self::normalize_array(merge_sorted(
+						&values, other, op,
+					));
+				},
+				_ if op == BitOp::And || op == BitOp::AndNot => {
+					let keep = op == BitOp::And;
+					values.retain(|&low| other.contains(low) == keep);
+					return Self::normalize_array(values);
+				},
+				_ => Self::blocks_from(&values),
+			},
+			Self::Bitmap(blocks, _) => blocks,
+		};
+		match other {
+			Self::Bitmap(other, _) => {
+				for (block, other) in blocks.iter_mut().zip(other.iter()) {
+					block.bitop_assign(other.as_bitslice(), op);
+				}
+			},
+			Self::Array(values) if op == BitOp::And => {
+				let values = values.iter().copied().filter(|&low| {
+					let (block, bit) = locate(low);
+					blocks[block][bit]
+				});
+				return Self::normalize_array(values.collect());
+			},
+			Self::Array(values) => {
+				for &low in values {
+					let (block, bit) = locate(low);
+					let new = op.apply(blocks[block][bit], true);
+					blocks[block].set(bit, new);
+				}
+			},
+		}
+		let count = blocks.iter().map(|block| block.count_ones()).sum();
+		Self::normalize_blocks(blocks, count)
+	}
+
+	/// Tests if every member of `self` is a member of `other`.
+	fn is_subset(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Array(values), _) => {
+				values.iter().all(|&low| other.contains(low))
+			},
+			//  A bitmap always has more members than an array.
+			(Self::Bitmap(..), Self::Array(_)) => false,
+			(Self::Bitmap(a, _), Self::Bitmap(b, _)) => {
+				a.iter().zip(b.iter()).all(|(a, b)| a.is_subset_of(b))
+			},
+		}
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	fn is_disjoint(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Array(values), other) | (other, Self::Array(values)) => {
+				values.iter().all(|&low| !other.contains(low))
+			},
+			(Self::Bitmap(a, _), Self::Bitmap(b, _)) => {
+				a.iter().zip(b.iter()).all(|(a, b)| a.is_disjoint(b))
+			},
+		}
+	}
+
+	/// Builds bitmap blocks from a list of members.
+	fn blocks_from(values: &[u16]) -> Box<[Block]> {
+		let mut blocks = Self::empty_blocks();
+		for &low in values {
+			let (block, bit) = locate(low);
+			blocks[block].set(bit, true);
+		}
+		blocks
+	}
+
+	/// Builds a bitmap container from a sorted, deduplicated, list of members.
+	fn bitmap_from(values: &[u16]) -> Self {
+		Self::Bitmap(Self::blocks_from(values), values.len())
+	}
+
+	/// Selects the representation for a sorted list of members.
+	fn normalize_array(values: Vec<u16>) -> Option<Self> {
+		match values.len() {
+			0 => None,
+			n if n <= ARRAY_MAX => Some(Self::Array(values)),
+			_ => Some(Self::bitmap_from(&values)),
+		}
+	}
+
+	/// Selects the representation for a bitmap with `count` members.
+	fn normalize_blocks(blocks: Box<[Block]>, count: usize) -> Option<Self> {
+		match count {
+			0 => None,
+			n if n <= ARRAY_MAX => {
+				Some(Self::Array(Self::iter_blocks(&blocks).collect()))
+			},
+			n => Some(Self::Bitmap(blocks, n)),
+		}
+	}
+}
+
+/// Iterates over the members of one container.
+#[derive(Clone, Debug)]
+enum ContainerIter<'a> {
+	/// The members of an array container.
+	Array(slice::Iter<'a, u16>),
+	/// The members of a bitmap container.
+	Bitmap {
+		/// The blocks not yet begun, and their positions in the container.
+		blocks: Enumerate<slice::Iter<'a, Block>>,
+		/// The index of the current block's first bit, and its remaining `1`
+		/// bits.
+		front: Option<(usize, IterOnes<'a, Lsb0, u32>)>,
+	},
+}
+
+impl Iterator for ContainerIter<'_> {
+	type Item = u16;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			Self::Array(values) => values.next().copied(),
+			Self::Bitmap { blocks, front } => loop {
+				if let Some((base, ref mut ones)) = *front {
+					if let Some(bit) = ones.next() {
+						return Some((base + bit) as u16);
+					}
+				}
+				let (idx, block) = blocks.next()?;
+				*front = Some((idx * BLOCK_BITS, block.iter_ones()));
+			},
+		}
+	}
+}
+
+/// Splits a value into its chunk key and its position within the chunk.
+#[inline]
+fn split(value: u32) -> (u16, u16) {
+	((value >> 16) as u16, value as u16)
+}
+
+/// Reassembles a value from its chunk key and position.
+#[inline]
+fn join(high: u16, low: u16) -> u32 {
+	(high as u32) << 16 | low as u32
+}
+
+/// Finds the block and bit index of a position in a bitmap container.
+#[inline]
+fn locate(low: u16) -> (usize, usize) {
+	let low = low as usize;
+	(low / BLOCK_BITS, low % BLOCK_BITS)
+}
+
+/// Combines two sorted, deduplicated, lists of members.
+fn merge_sorted(a: &[u16], b: &[u16], op: BitOp) -> Vec<u16> {
+	let mut out = Vec::with_capacity(match op {
+		BitOp::And => cmp::min(a.len(), b.len()),
+		BitOp::AndNot => a.len(),
+		BitOp::Or | BitOp::Xor => a.len() + b.len(),
+	});
+	let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+	loop {
+		let (value, in_a, in_b) = match (a.peek(), b.peek()) {
+			(None, None) => break,
+			(Some(&&x), None) => (x, true, false),
+			(None, Some(&&y)) => (y, false, true),
+			(Some(&&x), Some(&&y)) => match x.cmp(&y) {
+				Ordering::Less => (x, true, false),
+				Ordering::Greater => (y, false, true),
+				Ordering::Equal => (x, true, true),
+			},
+		};
+		if in_a {
+			a.next();
+		}
+		if in_b {
+			b.next();
+		}
+		if op.apply(in_a, in_b) {
+			out.push(value);
+		}
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn membership() {
+		let mut set = RoaringBitSet::new();
+		assert!(set.is_empty());
+
+		//  Fill one chunk past the array limit, then drain it back below.
+		for n in 0 .. ARRAY_MAX as u32 + 10 {
+			assert!(set.insert(0x0003_0000 + n * 3));
+		}
+		assert!(matches!(set.containers[0], Container::Bitmap(..)));
+		assert_eq!(set.len(), ARRAY_MAX as u64 + 10);
+		assert!(set.contains(0x0003_0006));
+		assert!(!set.contains(0x0003_0007));
+		assert!(!set.insert(0x0003_0006));
+
+		for n in 0 .. 10 {
+			assert!(set.remove(0x0003_0000 + n * 3));
+		}
+		assert!(!set.remove(0x0003_0000));
+		assert!(matches!(set.containers[0], Container::Array(_)));
+		assert_eq!(set.len(), ARRAY_MAX as u64);
+
+		assert!(set.insert(u32::MAX));
+		assert_eq!(set.iter().last(), Some(u32::MAX));
+		assert!(set.remove(u32::MAX));
+		assert_eq!(set.keys, [3]);
+
+		set.clear();
+		assert!(set.is_empty());
+		assert_eq!(set, RoaringBitSet::default());
+	}
+
+	#[test]
+	fn conversion() {
+		let mut bits = bitvec![Msb0, u16; 0; 3 * CHUNK_BITS + 5];
+		//  A dense chunk, a sparse chunk, and a chunk with only its last bit.
+		for idx in (0 .. CHUNK_BITS).filter(|n| n % 7 != 0) {
+			bits.set(idx, true);
+		}
+		bits.set(CHUNK_BITS + 1000, true);
+		bits.set(3 * CHUNK_BITS + 4, true);
+
+		let set = RoaringBitSet::from(bits.as_bitslice());
+		assert_eq!(set.keys, [0, 1, 3]);
+		assert!(matches!(set.containers[0], Container::Bitmap(..)));
+		assert_eq!(set.len(), bits.count_ones() as u64);
+		assert!(set.iter().eq(bits.iter_ones().map(|n| n as u32)));
+
+		assert_eq!(set.to_bitvec::<Msb0, u16>(), bits);
+		assert_eq!(set.to_bitvec::<Lsb0, u8>(), bits);
+		assert!(RoaringBitSet::new().to_bitvec::<Lsb0, usize>().is_empty());
+	}
+
+	#[test]
+	fn algebra() {
+		//  Multiples of 2 are dense in every chunk; multiples of 30 are sparse.
+		let evens = (0 .. 200_000).step_by(2).collect::<RoaringBitSet>();
+		let sparse = (0 .. 300_000).step_by(30).collect::<RoaringBitSet>();
+		let triples = (0 .. 200_000).step_by(3).collect::<RoaringBitSet>();
+		let sixes = (0 .. 200_000).step_by(6).collect::<RoaringBitSet>();
+
+		let mut set = evens.clone();
+		set.intersect_with(&triples);
+		assert_eq!(set, sixes);
+		assert!(set.is_subset(&evens) && set.is_subset(&triples));
+		assert!(evens.is_superset(&set));
+		assert!(!evens.is_subset(&set));
+
+		let mut set = evens.clone();
+		set.intersect_with(&sparse);
+		assert!(set.iter().eq((0 .. 200_000).step_by(30)));
+		let mut set = sparse.clone();
+		set.intersect_with(&evens);
+		assert!(set.iter().eq((0 .. 200_000).step_by(30)));
+		let mut set = sparse.clone();
+		set.difference_with(&evens);
+		assert!(set.iter().eq((200_010 .. 300_000).step_by(30)));
+
+		let mut set = evens.clone();
+		set.union_with(&sparse);
+		assert!(set.iter().all(|n| n % 2 == 0));
+		assert_eq!(set.len(), 100_000 + 10_000 - 200_000 / 30 - 1);
+
+		let mut set = evens.clone();
+		set.difference_with(&triples);
+		assert!(set.is_disjoint(&triples));
+		assert!(set.is_disjoint(&sixes));
+		assert_eq!(set.len(), 100_000 - sixes.len());
+
+		let mut set = evens.clone();
+		set.symmetric_difference_with(&triples);
+		assert!(set.is_disjoint(&sixes));
+		assert_eq!(set.len(), 100_000 + triples.len() - 2 * sixes.len());
+
+		//  Emptied chunks are dropped.
+		let mut set = evens.clone();
+		set.difference_with(&evens);
+		assert!(set.is_empty());
+		let mut set = sparse.clone();
+		set.symmetric_difference_with(&sparse);
+		assert!(set.is_empty());
+
+		let bits = evens.to_bitvec::<Lsb0, usize>();
+		let naive = bits.iter_ones().filter(|n| n % 3 == 0).count();
+		assert_eq!(naive as u64, sixes.len());
+	}
+}
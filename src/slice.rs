@@ -43,6 +43,7 @@ use alloc::vec::Vec;
 #[cfg(feature = "alloc")]
 use core::mem::ManuallyDrop;
 use core::{
+	cmp,
 	marker::PhantomData,
 	ops::RangeBounds,
 	ptr,
@@ -56,6 +57,7 @@ use funty::{
 #[cfg(feature = "alloc")]
 use tap::pipe::Pipe;
 
+pub(crate) use self::specialization::BitOp;
 use crate::{
 	access::{
 		BitAccess,
@@ -1235,6 +1237,306 @@ where
 		self.iter_zeros().next_back()
 	}
 
+	/// Gets the index of the first bit at or after `from` that is set to `1`.
+	///
+	/// This searches `self[from ..]` with the same specialized scan as
+	/// [`.first_one()`], and so skips whole elements at a time for the [`Lsb0`]
+	/// and [`Msb0`] orderings.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `from`: The index at which to begin the search. It is included in the
+	///   search.
+	///
+	/// # Returns
+	///
+	/// The index, in `self`, of the first `1` bit at or after `from`, or `None`
+	/// if there is no such bit. This is `None` when `from` is not less than
+	/// `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 0];
+	/// assert_eq!(bits.next_one(1), Some(1));
+	/// assert_eq!(bits.next_one(2), Some(4));
+	/// assert!(bits.next_one(5).is_none());
+	/// assert!(bits.next_one(10).is_none());
+	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	/// [`.first_one()`]: Self::first_one
+	#[inline]
+	pub fn next_one(&self, from: usize) -> Option<usize> {
+		self.get(from ..)?.first_one().map(|idx| idx + from)
+	}
+
+	/// Gets the index of the first bit at or after `from` that is set to `0`.
+	///
+	/// This searches `self[from ..]` with the same specialized scan as
+	/// [`.first_zero()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `from`: The index at which to begin the search. It is included in the
+	///   search.
+	///
+	/// # Returns
+	///
+	/// The index, in `self`, of the first `0` bit at or after `from`, or `None`
+	/// if there is no such bit. This is `None` when `from` is not less than
+	/// `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0, 1];
+	/// assert_eq!(bits.next_zero(1), Some(1));
+	/// assert_eq!(bits.next_zero(2), Some(4));
+	/// assert!(bits.next_zero(5).is_none());
+	/// ```
+	///
+	/// [`.first_zero()`]: Self::first_zero
+	#[inline]
+	pub fn next_zero(&self, from: usize) -> Option<usize> {
+		self.get(from ..)?.first_zero().map(|idx| idx + from)
+	}
+
+	/// Gets the index of the last bit before `before` that is set to `1`.
+	///
+	/// This searches `self[.. before]` with the same specialized scan as
+	/// [`.last_one()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `before`: The index at which to end the search. It is excluded from
+	///   the search. Values greater than `self.len()` search the whole
+	///   bit-slice.
+	///
+	/// # Returns
+	///
+	/// The index of the last `1` bit before `before`, or `None` if there is no
+	/// such bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 0];
+	/// assert_eq!(bits.prev_one(4), Some(1));
+	/// assert_eq!(bits.prev_one(5), Some(4));
+	/// assert_eq!(bits.prev_one(10), Some(4));
+	/// assert!(bits.prev_one(1).is_none());
+	/// ```
+	///
+	/// [`.last_one()`]: Self::last_one
+	#[inline]
+	pub fn prev_one(&self, before: usize) -> Option<usize> {
+		let before = cmp::min(before, self.len());
+		unsafe { self.get_unchecked(.. before) }.last_one()
+	}
+
+	/// Gets the index of the last bit before `before` that is set to `0`.
+	///
+	/// This searches `self[.. before]` with the same specialized scan as
+	/// [`.last_zero()`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `before`: The index at which to end the search. It is excluded from
+	///   the search. Values greater than `self.len()` search the whole
+	///   bit-slice.
+	///
+	/// # Returns
+	///
+	/// The index of the last `0` bit before `before`, or `None` if there is no
+	/// such bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0, 1];
+	/// assert_eq!(bits.prev_zero(4), Some(1));
+	/// assert_eq!(bits.prev_zero(5), Some(4));
+	/// assert!(bits.prev_zero(1).is_none());
+	/// ```
+	///
+	/// [`.last_zero()`]: Self::last_zero
+	#[inline]
+	pub fn prev_zero(&self, before: usize) -> Option<usize> {
+		let before = cmp::min(before, self.len());
+		unsafe { self.get_unchecked(.. before) }.last_zero()
+	}
+
+	/// Finds the first run of `len` consecutive bits set to `value`.
+	///
+	/// The search moves from one run of `value` bits to the next with
+	/// [`.next_one()`] and [`.next_zero()`], so for the [`Lsb0`] and [`Msb0`]
+	/// orderings it skips whole memory elements that cannot start or end a run,
+	/// rather than inspecting each bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The bit value that every bit in the run must have.
+	/// - `len`: The number of bits in the run.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit in the first run of at least `len` bits equal
+	/// to `value`, or `None` if there is no such run. A run of length `0` is
+	/// always found at index `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 1, 0, 0, 0, 1];
+	/// assert_eq!(bits.find_run(false, 2), Some(1));
+	/// assert_eq!(bits.find_run(false, 3), Some(4));
+	/// assert!(bits.find_run(false, 4).is_none());
+	/// assert_eq!(bits.find_run(true, 1), Some(0));
+	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	/// [`.next_one()`]: Self::next_one
+	/// [`.next_zero()`]: Self::next_zero
+	#[inline]
+	pub fn find_run(&self, value: bool, len: usize) -> Option<usize> {
+		self.find_run_in(value, len, 0, 1)
+	}
+
+	/// Finds the first run of `len` consecutive bits set to `value` that
+	/// begins at or after `from`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The bit value that every bit in the run must have.
+	/// - `len`: The number of bits in the run.
+	/// - `from`: The lowest index at which the run may begin.
+	///
+	/// # Returns
+	///
+	/// The index, in `self`, of the first bit in the run, or `None` if there is
+	/// no such run. This is `None` when `from` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 1, 0, 0, 0, 1];
+	/// assert_eq!(bits.find_run_from(false, 2, 2), Some(4));
+	/// assert_eq!(bits.find_run_from(false, 2, 5), Some(5));
+	/// assert!(bits.find_run_from(false, 2, 6).is_none());
+	/// ```
+	#[inline]
+	pub fn find_run_from(
+		&self,
+		value: bool,
+		len: usize,
+		from: usize,
+	) -> Option<usize> {
+		self.find_run_in(value, len, from, 1)
+	}
+
+	/// Finds the first run of `len` consecutive bits set to `value` that
+	/// begins at a multiple of `align`.
+	///
+	/// Allocators use this to place a block on a boundary within a free-map.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The bit value that every bit in the run must have.
+	/// - `len`: The number of bits in the run.
+	/// - `align`: The run must begin at an index that is a multiple of this.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit in the run, or `None` if there is no such
+	/// run.
+	///
+	/// # Panics
+	///
+	/// This panics if `align` is `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 0, 1, 0, 0, 1];
+	/// assert_eq!(bits.find_run(false, 2), Some(1));
+	/// assert_eq!(bits.find_run_aligned(false, 2, 2), Some(2));
+	/// assert!(bits.find_run_aligned(false, 2, 4).is_none());
+	/// ```
+	#[inline]
+	pub fn find_run_aligned(
+		&self,
+		value: bool,
+		len: usize,
+		align: usize,
+	) -> Option<usize> {
+		assert!(align != 0, "Run alignment must be nonzero");
+		self.find_run_in(value, len, 0, align)
+	}
+
+	/// Finds the first run of `len` bits equal to `value` that begins at an
+	/// index not less than `from` and divisible by `align`.
+	fn find_run_in(
+		&self,
+		value: bool,
+		len: usize,
+		from: usize,
+		align: usize,
+	) -> Option<usize> {
+		let bits = self.len();
+		let mut cursor = from;
+		loop {
+			//  Round up to the next permitted starting index.
+			cursor = cursor.checked_add(align - 1)? / align * align;
+			if len == 0 {
+				return if cursor <= bits { Some(cursor) } else { None };
+			}
+			let start = if value {
+				self.next_one(cursor)
+			}
+			else {
+				self.next_zero(cursor)
+			}?;
+			let start = start.checked_add(align - 1)? / align * align;
+			//  A run shorter than `len` cannot be extended, so the search
+			//  resumes at the first bit past its end.
+			let end = if value {
+				self.next_zero(start)
+			}
+			else {
+				self.next_one(start)
+			}
+			.unwrap_or(bits);
+			if start <= end && end - start >= len {
+				return Some(start);
+			}
+			cursor = end;
+		}
+	}
+
 	/// Counts the number of bits from the start of the bit-slice to the first
 	/// bit set to `0`.
 	///
@@ -1313,6 +1615,58 @@ where
 		self.last_one().map(|idx| self.len() - 1 - idx).unwrap_or(0)
 	}
 
+	/// Enumerates the runs of identical bits in the bit-slice.
+	///
+	/// Each run is reported as the value of its bits and the range of indices
+	/// it covers. Runs are found by searching for the next bit of the opposite
+	/// value, which scans whole memory elements at a time for the [`Lsb0`] and
+	/// [`Msb0`] orderings. The iterator can also be consumed from the back.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// let mut runs = bits.iter_runs();
+	///
+	/// assert_eq!(runs.next(), Some((false, 0 .. 2)));
+	/// assert_eq!(runs.next_back(), Some((true, 6 .. 7)));
+	/// assert_eq!(runs.next(), Some((true, 2 .. 5)));
+	/// assert_eq!(runs.next(), Some((false, 5 .. 6)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn iter_runs(&self) -> IterRuns<O, T> {
+		IterRuns::new(self)
+	}
+
+	/// Counts the runs of identical bits in the bit-slice.
+	///
+	/// This is the number of items that [`.iter_runs()`] produces: one more
+	/// than the number of places where adjacent bits differ, or zero if the
+	/// bit-slice is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![].count_runs(), 0);
+	/// assert_eq!(bits![1; 40].count_runs(), 1);
+	/// assert_eq!(bits![0, 0, 1, 1, 1, 0, 1].count_runs(), 4);
+	/// ```
+	///
+	/// [`.iter_runs()`]: Self::iter_runs
+	#[inline]
+	pub fn count_runs(&self) -> usize {
+		self.iter_runs().count()
+	}
+
 	/// Copies the bits from `src` into `self`.
 	///
 	/// The length of `src` must be the same as `self.
@@ -1729,6 +2083,11 @@ where
 
 	/// Sets all bits in the slice to a value.
 	///
+	/// The partial elements at either end of the slice are written with one
+	/// mask each, and the fully-spanned elements between them are filled with
+	/// `memset`, so this is fast on subslices as well as on whole buffers. Use
+	/// [`.fill_range()`] to fill a range of a slice without reborrowing it.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
@@ -1748,6 +2107,8 @@ where
 	/// bits[.. 1].set_all(true);
 	/// assert_eq!(bits.as_raw_slice(), &[0b1010_0100]);
 	/// ```
+	///
+	/// [`.fill_range()`]: Self::fill_range
 	pub fn set_all(&mut self, value: bool) {
 		//  Grab the function pointers used to commit bit-masks into memory.
 		let setter = <T::Access>::get_writers(value);
@@ -1774,6 +2135,197 @@ where
 		}
 	}
 
+	/// Sets all bits in a range of the slice to a value.
+	///
+	/// This is equivalent to `self[range].set_all(value)`: the partial
+	/// elements at either end of the range are written with one mask each, and
+	/// the elements between them are filled with `memset`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The range of indices to set.
+	/// - `value`: The bit value to which all bits in the range will be set.
+	///
+	/// # Panics
+	///
+	/// This panics if the range does not run from lower to higher, or extends
+	/// past `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 4];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// bits.fill_range(4 .. 28, true);
+	/// assert_eq!(data, [0x0F, 0xFF, 0xFF, 0xF0]);
+	/// ```
+	#[inline]
+	pub fn fill_range<R>(&mut self, range: R, value: bool)
+	where R: RangeBounds<usize> {
+		let range = dvl::normalize_range(range, self.len());
+		dvl::assert_range(range.clone(), self.len());
+		unsafe { self.get_unchecked_mut(range) }.set_all(value);
+	}
+
+	/// Sets all bits in a range of the slice to a value.
+	///
+	/// This is another name for [`.fill_range()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut free = bitvec![0; 16];
+	/// let start = free.find_run(false, 5).unwrap();
+	/// free.set_range(start .. start + 5, true);
+	/// assert_eq!(free.find_run(false, 5), Some(5));
+	/// assert_eq!(free.count_ones(), 5);
+	/// ```
+	///
+	/// [`.fill_range()`]: Self::fill_range
+	#[inline(always)]
+	#[cfg(not(tarpaulin_include))]
+	pub fn set_range<R>(&mut self, range: R, value: bool)
+	where R: RangeBounds<usize> {
+		self.fill_range(range, value);
+	}
+
+	/// Inverts all bits in a range of the slice.
+	///
+	/// Like [`.fill_range()`], this inverts the partial elements at either end
+	/// of the range with one mask each, and inverts the elements between them
+	/// whole.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The range of indices to invert.
+	///
+	/// # Panics
+	///
+	/// This panics if the range does not run from lower to higher, or extends
+	/// past `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0x0Fu8, 0xF0];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// bits.toggle_range(2 .. 14);
+	/// assert_eq!(data, [0x30, 0x0C]);
+	/// ```
+	///
+	/// [`.fill_range()`]: Self::fill_range
+	#[inline]
+	pub fn toggle_range<R>(&mut self, range: R)
+	where R: RangeBounds<usize> {
+		let range = dvl::normalize_range(range, self.len());
+		dvl::assert_range(range.clone(), self.len());
+		let _ = !unsafe { self.get_unchecked_mut(range) };
+	}
+
+	/// Deposits the bits of `src`, in order, into the bits of `self` that are
+	/// selected by `mask`.
+	///
+	/// This is the bit-slice analogue of the x86 BMI2 `pdep` instruction,
+	/// except that the bits of `self` that `mask` does not select are left
+	/// unchanged rather than cleared. It is the inverse of [`.gather()`].
+	///
+	/// When all three bit-slices use [`Lsb0`], or all three use [`Msb0`], this
+	/// works on 64-bit chunks of `self` at a time, and the `bmi2` feature
+	/// allows it to use the `pdep` instruction on x86-64 processors that have
+	/// it.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mask`: Selects the bits of `self` to write. It must have the same
+	///   length as `self`.
+	/// - `src`: The bits to write. Its first `mask.count_ones()` bits are
+	///   written, in order, to the selected bits of `self`, and any bits after
+	///   them are ignored.
+	///
+	/// # Panics
+	///
+	/// This panics if `mask` and `self` have different lengths, or if `src` is
+	/// shorter than `mask.count_ones()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 0, 0, 1, 1, 1, 1];
+	/// let mask = bits![1, 0, 1, 0, 1, 0, 1, 0];
+	/// bits.scatter(mask, bits![1, 1, 0, 0]);
+	/// assert_eq!(bits, bits![1, 0, 1, 0, 0, 1, 0, 1]);
+	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	/// [`.gather()`]: Self::gather
+	pub fn scatter<O2, T2, O3, T3>(
+		&mut self,
+		mask: &BitSlice<O2, T2>,
+		src: &BitSlice<O3, T3>,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+		O3: BitOrder,
+		T3: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			mask.len(),
+			"Scattering requires a mask of equal length"
+		);
+		let count = mask.count_ones();
+		assert!(
+			src.len() >= count,
+			"Scattering {} bits requires at least as many source bits, not {}",
+			count,
+			src.len()
+		);
+
+		if dvl::match_order::<O, Lsb0>()
+			&& dvl::match_order::<O2, Lsb0>()
+			&& dvl::match_order::<O3, Lsb0>()
+		{
+			let this: &mut BitSlice<Lsb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			let mask: &BitSlice<Lsb0, T2> =
+				unsafe { &*(mask as *const _ as *const _) };
+			let src: &BitSlice<Lsb0, T3> =
+				unsafe { &*(src as *const _ as *const _) };
+			this.sp_scatter(mask, src);
+		}
+		else if dvl::match_order::<O, Msb0>()
+			&& dvl::match_order::<O2, Msb0>()
+			&& dvl::match_order::<O3, Msb0>()
+		{
+			let this: &mut BitSlice<Msb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			let mask: &BitSlice<Msb0, T2> =
+				unsafe { &*(mask as *const _ as *const _) };
+			let src: &BitSlice<Msb0, T3> =
+				unsafe { &*(src as *const _ as *const _) };
+			this.sp_scatter(mask, src);
+		}
+		else {
+			for (idx, bit) in mask.iter_ones().zip(src.iter().by_val()) {
+				unsafe {
+					self.set_unchecked(idx, bit);
+				}
+			}
+		}
+	}
+
 	/// Applies a function to each bit in the slice.
 	///
 	/// `BitSlice` cannot implement [`IndexMut`], as it cannot manifest `&mut
@@ -1881,6 +2433,304 @@ where
 	}
 }
 
+/** Set-algebra queries between two bit-slices.
+
+These methods treat a bit-slice as a dense set of indices: an index is a member
+of the set when the bit at that index is `1`. The two bit-slices need not have
+the same length; all indices past the end of the shorter slice are considered to
+be absent from its set, as if it were extended with `0` bits.
+
+None of these methods allocate. When both bit-slices use the `Lsb0` or `Msb0`
+ordering, they walk both regions one memory element at a time, even if the two
+regions begin at different indices within their first elements or use different
+storage types. Other orderings fall back to a bit-by-bit walk.
+**/
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Tests if every bit set in `self` is also set in `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The bit-slice that may contain `self`.
+	///
+	/// # Returns
+	///
+	/// Whether `self` ⊆ `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 0, 1];
+	/// let b = bits![Msb0, u8; 1, 1, 0, 1, 0];
+	/// assert!(a.is_subset_of(b));
+	/// assert!(!b.is_subset_of(a));
+	/// assert!(bits![].is_subset_of(a));
+	/// ```
+	pub fn is_subset_of<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.zip_elements(other, |a, b| BitOp::And.apply(a, !b) == T::Mem::ZERO)
+			&& self.get(other.len() ..).map_or(true, BitSlice::not_any)
+	}
+
+	/// Tests if every bit set in `other` is also set in `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The bit-slice that `self` may contain.
+	///
+	/// # Returns
+	///
+	/// Whether `self` ⊇ `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 0, 1];
+	/// assert!(a.is_superset_of(bits![0, 1, 0, 1, 0, 0]));
+	/// assert!(!a.is_superset_of(bits![0, 0, 1]));
+	/// ```
+	#[inline]
+	pub fn is_superset_of<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		other.is_subset_of(self)
+	}
+
+	/// Tests if no index is set in both `self` and `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice.
+	///
+	/// # Returns
+	///
+	/// Whether `self` ∩ `other` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 0, 1];
+	/// assert!(a.is_disjoint(bits![1, 0, 1, 0, 1]));
+	/// assert!(!a.is_disjoint(bits![0, 0, 0, 1]));
+	/// ```
+	pub fn is_disjoint<O2, T2>(&self, other: &BitSlice<O2, T2>) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.zip_elements(other, |a, b| BitOp::And.apply(a, b) == T::Mem::ZERO)
+	}
+
+	/// Counts the indices set in both `self` and `other`.
+	///
+	/// This is the number of bits that would be set in `self & other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice.
+	///
+	/// # Returns
+	///
+	/// The size of `self` ∩ `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 1];
+	/// assert_eq!(a.intersection_count(bits![1, 1, 0, 1, 1]), 2);
+	/// ```
+	pub fn intersection_count<O2, T2>(&self, other: &BitSlice<O2, T2>) -> usize
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut count = 0;
+		self.zip_elements(other, |a, b| {
+			count += BitOp::And.apply(a, b).count_ones() as usize;
+			true
+		});
+		count
+	}
+
+	/// Counts the indices set in either `self` or `other`.
+	///
+	/// This is the number of bits that would be set in `self | other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice.
+	///
+	/// # Returns
+	///
+	/// The size of `self` ∪ `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0];
+	/// assert_eq!(a.union_count(bits![1, 1, 0, 0, 1]), 4);
+	/// ```
+	pub fn union_count<O2, T2>(&self, other: &BitSlice<O2, T2>) -> usize
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut count = 0;
+		self.zip_elements(other, |a, b| {
+			count += BitOp::Or.apply(a, b).count_ones() as usize;
+			true
+		});
+		count
+			+ self.get(other.len() ..).map_or(0, BitSlice::count_ones)
+			+ other.get(self.len() ..).map_or(0, BitSlice::count_ones)
+	}
+
+	/// Counts the indices set in `self` but not in `other`.
+	///
+	/// This is the number of bits that would be set in `self & !other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The bit-slice whose members are removed from `self`.
+	///
+	/// # Returns
+	///
+	/// The size of `self` \ `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// assert_eq!(a.difference_count(bits![1, 1, 0]), 2);
+	/// ```
+	pub fn difference_count<O2, T2>(&self, other: &BitSlice<O2, T2>) -> usize
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut count = 0;
+		self.zip_elements(other, |a, b| {
+			count += BitOp::And.apply(a, !b).count_ones() as usize;
+			true
+		});
+		count + self.get(other.len() ..).map_or(0, BitSlice::count_ones)
+	}
+
+	/// Counts the indices at which `self` and `other` differ.
+	///
+	/// This is the number of bits that would be set in `self ^ other`. When
+	/// the two bit-slices have the same length, this is their Hamming
+	/// distance.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Another bit-slice.
+	///
+	/// # Returns
+	///
+	/// The size of the symmetric difference of `self` and `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0];
+	/// assert_eq!(a.hamming_distance(bits![0, 0, 1, 1]), 2);
+	/// assert_eq!(a.hamming_distance(bits![0, 1, 1, 0, 1]), 1);
+	/// ```
+	pub fn hamming_distance<O2, T2>(&self, other: &BitSlice<O2, T2>) -> usize
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut count = 0;
+		self.zip_elements(other, |a, b| {
+			count += BitOp::Xor.apply(a, b).count_ones() as usize;
+			true
+		});
+		count
+			+ self.get(other.len() ..).map_or(0, BitSlice::count_ones)
+			+ other.get(self.len() ..).map_or(0, BitSlice::count_ones)
+	}
+
+	/// Walks the common prefix of `self` and `other` together, one memory
+	/// element of `self` at a time.
+	///
+	/// `func` receives the live bits of an element of `self`, and the bits of
+	/// `other` at the same indices in the same positions, with all other bits
+	/// cleared. The walk stops as soon as `func` returns `false`.
+	///
+	/// # Returns
+	///
+	/// Whether the walk visited the entire common prefix.
+	fn zip_elements<O2, T2, F>(
+		&self,
+		other: &BitSlice<O2, T2>,
+		mut func: F,
+	) -> bool
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		F: FnMut(T::Mem, T::Mem) -> bool,
+	{
+		let len = cmp::min(self.len(), other.len());
+		let this = unsafe { self.get_unchecked(.. len) };
+		let that = unsafe { other.get_unchecked(.. len) };
+
+		//  See `.copy_from_bitslice()` for the rationale of the pointer casts.
+		if dvl::match_order::<O, Lsb0>() && dvl::match_order::<O2, Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(this as *const _ as *const _) };
+			let that: &BitSlice<Lsb0, T2> =
+				unsafe { &*(that as *const _ as *const _) };
+			this.sp_zip_elements(that, func)
+		}
+		else if dvl::match_order::<O, Msb0>() && dvl::match_order::<O2, Msb0>()
+		{
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(this as *const _ as *const _) };
+			let that: &BitSlice<Msb0, T2> =
+				unsafe { &*(that as *const _ as *const _) };
+			this.sp_zip_elements(that, func)
+		}
+		else {
+			let bit = |bit: bool| if bit { T::Mem::ONE } else { T::Mem::ZERO };
+			this.iter()
+				.by_val()
+				.zip(that.iter().by_val())
+				.all(|(a, b)| func(bit(a), bit(b)))
+		}
+	}
+}
+
 /// Unchecked variants of checked accessors.
 impl<O, T> BitSlice<O, T>
 where
@@ -2653,6 +3503,87 @@ where
 			)
 		}
 	}
+
+	/// Extracts the bits of `self` that are selected by `mask` into a new
+	/// [`BitVec`], in order.
+	///
+	/// This is the bit-slice analogue of the x86 BMI2 `pext` instruction. It is
+	/// the inverse of [`.scatter()`].
+	///
+	/// When both bit-slices use [`Lsb0`], or both use [`Msb0`], this works on
+	/// 64-bit chunks of `self` at a time, and the `bmi2` feature allows it to
+	/// use the `pext` instruction on x86-64 processors that have it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mask`: Selects the bits of `self` to extract. It must have the same
+	///   length as `self`.
+	///
+	/// # Returns
+	///
+	/// A bit-vector of length `mask.count_ones()`, holding the selected bits of
+	/// `self` in index order.
+	///
+	/// # Panics
+	///
+	/// This panics if `mask` and `self` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 0, 0, 1, 0, 1];
+	/// let mask = bits![1, 0, 1, 0, 1, 0, 1, 0];
+	/// assert_eq!(bits.gather(mask), bits![1, 1, 0, 0]);
+	/// ```
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	/// [`.scatter()`]: Self::scatter
+	pub fn gather<O2, T2>(
+		&self,
+		mask: &BitSlice<O2, T2>,
+	) -> BitVec<O, T::Unalias>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			mask.len(),
+			"Gathering requires a mask of equal length"
+		);
+
+		let mut out = BitVec::repeat(false, mask.count_ones());
+		if dvl::match_order::<O, Lsb0>() && dvl::match_order::<O2, Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			let mask: &BitSlice<Lsb0, T2> =
+				unsafe { &*(mask as *const _ as *const _) };
+			let dst: &mut BitSlice<Lsb0, T::Unalias> =
+				unsafe { &mut *(out.as_mut_bitslice() as *mut _ as *mut _) };
+			this.sp_gather(mask, dst);
+		}
+		else if dvl::match_order::<O, Msb0>() && dvl::match_order::<O2, Msb0>()
+		{
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			let mask: &BitSlice<Msb0, T2> =
+				unsafe { &*(mask as *const _ as *const _) };
+			let dst: &mut BitSlice<Msb0, T::Unalias> =
+				unsafe { &mut *(out.as_mut_bitslice() as *mut _ as *mut _) };
+			this.sp_gather(mask, dst);
+		}
+		else {
+			for (dst, idx) in out.iter_mut().zip(mask.iter_ones()) {
+				dst.set(unsafe { *self.get_unchecked(idx) });
+			}
+		}
+		out
+	}
 }
 
 /** Performs the same functionality as [`from_raw_parts`], without checking the
@@ -2726,6 +3657,7 @@ where
 mod api;
 mod iter;
 mod ops;
+mod search;
 mod specialization;
 mod traits;
 
@@ -2747,6 +3679,7 @@ pub use self::{
 		Iter,
 		IterMut,
 		IterOnes,
+		IterRuns,
 		IterZeros,
 		RChunks,
 		RChunksExact,
@@ -2762,6 +3695,11 @@ pub use self::{
 		SplitNMut,
 		Windows,
 	},
+	search::{
+		ApproxMatches,
+		MatchIndices,
+		SplitPattern,
+	},
 };
 
 #[cfg(test)]
@@ -28,7 +28,11 @@ use crate::{
 		Const,
 		Mut,
 	},
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	ptr::{
 		BitPtr,
 		BitPtrRange,
@@ -57,6 +61,7 @@ use crate::{
 			SplitNMut,
 			Windows,
 		},
+		search,
 		BitRef,
 		BitSlice,
 	},
@@ -585,6 +590,11 @@ where
 
 	/// Reverses the order of bits in the slice, in place.
 	///
+	/// For the [`Lsb0`] and [`Msb0`] orderings, this exchanges word-sized
+	/// chunks from each end of the slice, reversing each with a single
+	/// `reverse_bits` instruction. Other orderings swap one pair of bits at a
+	/// time.
+	///
 	/// # Original
 	///
 	/// [`slice::reverse`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.reverse)
@@ -598,8 +608,22 @@ where
 	/// v.reverse();
 	/// assert_eq!(v, bits![1, 1, 0]);
 	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
 	#[inline]
 	pub fn reverse(&mut self) {
+		if dvl::match_order::<O, Lsb0>() {
+			let this: &mut BitSlice<Lsb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			return this.sp_reverse();
+		}
+		else if dvl::match_order::<O, Msb0>() {
+			let this: &mut BitSlice<Msb0, T> =
+				unsafe { &mut *(self as *mut _ as *mut _) };
+			return this.sp_reverse();
+		}
+
 		/* This would be better written as a recursive algorithm that swaps the
 		edge bits and recurses on `[1 .. len - 1]`, but Rust does not guarantee
 		tail-call optimization, and manual iteration allows for slight
@@ -1643,6 +1667,8 @@ where
 	/// This searches for a matching subslice (allowing different type
 	/// parameters) rather than for a specific bit. Searching for a contained
 	/// element with a given value is not as useful on a collection of `bool`.
+	/// It is [`.find()`]`.is_some()`, and so compares 64-bit words rather than
+	/// individual bits.
 	///
 	/// Furthermore, `BitSlice` defines [`any`] and [`not_all`], which are
 	/// optimized searchers for any `true` or `false` bit, respectively, in a
@@ -1665,17 +1691,14 @@ where
 	///
 	/// [`any`]: Self::any
 	/// [`not_all`]: Self::not_all
+	/// [`.find()`]: Self::find
 	#[inline]
 	pub fn contains<O2, T2>(&self, x: &BitSlice<O2, T2>) -> bool
 	where
 		O2: BitOrder,
 		T2: BitStore,
 	{
-		let len = x.len();
-		if len > self.len() {
-			return false;
-		};
-		self.windows(len).any(|s| s == x)
+		self.find(x).is_some()
 	}
 
 	/// Returns `true` if `needle` is a prefix of the slice.
@@ -1712,8 +1735,7 @@ where
 		O2: BitOrder,
 		T2: BitStore,
 	{
-		let len = needle.len();
-		self.len() >= len && needle == unsafe { self.get_unchecked(.. len) }
+		self.len() >= needle.len() && search::matches_at(self, 0, needle)
 	}
 
 	/// Returns `true` if `needle` is a suffix of the slice.
@@ -1750,9 +1772,8 @@ where
 		O2: BitOrder,
 		T2: BitStore,
 	{
-		let nlen = needle.len();
-		let len = self.len();
-		len >= nlen && needle == unsafe { self.get_unchecked(len - nlen ..) }
+		let (len, nlen) = (self.len(), needle.len());
+		len >= nlen && search::matches_at(self, len - nlen, needle)
 	}
 
 	/// Rotates the slice in-place such that the first `by` bits of the slice
@@ -10,6 +10,7 @@ use core::{
 	iter::FusedIterator,
 	marker::PhantomData,
 	mem,
+	ops::Range,
 };
 
 use crate::{
@@ -40,7 +41,12 @@ where
 	T: BitStore,
 {
 	type IntoIter = Iter<'a, O, T>;
-	type Item = <Self::IntoIter as Iterator>::Item;
+	/* This is written out, rather than projected from `Self::IntoIter`, so
+	that coherence can see that `&BitSlice` is not an iterator of `bool`. The
+	operator traits in `ops` rely on this to implement both `&BitSlice` and
+	`IntoIterator<Item = bool>` right-hand sides.
+	*/
+	type Item = BitRef<'a, Const, O, T>;
 
 	fn into_iter(self) -> Self::IntoIter {
 		Iter::new(self)
@@ -2544,6 +2550,121 @@ where
 {
 }
 
+/** Enumerates the runs of identical bits in a [`BitSlice`].
+
+Each item is the value shared by every bit in a run, and the range of indices
+the run covers. Adjacent runs always have opposite values, and together the
+runs cover the entire bit-slice.
+
+This struct is created by the [`.iter_runs()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_runs()`]: crate::slice::BitSlice::iter_runs
+**/
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct IterRuns<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The remaining slice whose runs are to be found.
+	inner: &'a BitSlice<O, T>,
+	/// The offset from the front of the original slice to current `inner`.
+	front: usize,
+}
+
+impl<'a, O, T> IterRuns<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(super) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Default for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Iterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = (bool, Range<usize>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let bit = *self.inner.first()?;
+		//  The run ends at the first bit of the opposite value. Both searches
+		//  scan whole elements where the ordering permits.
+		let len = if bit {
+			self.inner.first_zero()
+		}
+		else {
+			self.inner.first_one()
+		}
+		.unwrap_or_else(|| self.inner.len());
+
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(len) };
+		self.inner = rest;
+		let start = self.front;
+		self.front += len;
+		Some((bit, start .. self.front))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		((len != 0) as usize, Some(len))
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let bit = *self.inner.last()?;
+		let start = if bit {
+			self.inner.last_zero()
+		}
+		else {
+			self.inner.last_one()
+		}
+		.map(|idx| idx + 1)
+		.unwrap_or(0);
+
+		let end = self.front + self.inner.len();
+		let (rest, _) = unsafe { self.inner.split_at_unchecked(start) };
+		self.inner = rest;
+		Some((bit, self.front + start .. end))
+	}
+}
+
+impl<O, T> FusedIterator for IterRuns<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
 /* This macro has some very obnoxious call syntax that is necessary to handle
 the different iteration protocols used above.
 
@@ -1,25 +1,36 @@
 //! Port of the `[T]` operator implementations.
 
-use core::ops::{
-	BitAndAssign,
-	BitOrAssign,
-	BitXorAssign,
-	Index,
-	IndexMut,
-	Not,
-	Range,
-	RangeFrom,
-	RangeFull,
-	RangeInclusive,
-	RangeTo,
-	RangeToInclusive,
+use core::{
+	cmp,
+	ops::{
+		BitAndAssign,
+		BitOrAssign,
+		BitXorAssign,
+		Index,
+		IndexMut,
+		Not,
+		Range,
+		RangeFrom,
+		RangeFull,
+		RangeInclusive,
+		RangeTo,
+		RangeToInclusive,
+		ShlAssign,
+		ShrAssign,
+	},
 };
 
 use crate::{
 	access::BitAccess,
+	devel as dvl,
 	domain::DomainMut,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	slice::{
+		specialization::BitOp,
 		BitSlice,
 		BitSliceIndex,
 	},
@@ -62,6 +73,112 @@ where
 	}
 }
 
+/** Performs a bitwise `&` between two bit-slices, using whole-element reads
+and writes where possible.
+
+Unlike the implementation for arbitrary `bool` iterators, this is able to read
+from `rhs` and write into `self` an entire memory element at a time when both
+slices use the `Lsb0` or `Msb0` ordering. The two slices do not need to share a
+storage type, nor start at the same index within their first elements.
+
+If `rhs` is shorter than `self`, then the bits of `self` past the end of `rhs`
+are cleared, as if `rhs` had been extended with `0`. Bits of `rhs` past the end
+of `self` are ignored.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let lhs = bits![mut 0, 0, 1, 1, 0, 1];
+let rhs = bits![Lsb0, u8; 1, 0, 1, 0, 1, 1, 1];
+*lhs &= &rhs[1 ..];
+assert_eq!(lhs, bits![0, 0, 0, 1, 0, 1]);
+```
+**/
+impl<'a, O, T, O2, T2> BitAndAssign<&'a BitSlice<O2, T2>> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	#[inline]
+	fn bitand_assign(&mut self, rhs: &'a BitSlice<O2, T2>) {
+		self.bitop_assign(rhs, BitOp::And);
+	}
+}
+
+/** Performs a bitwise `|` between two bit-slices, using whole-element reads
+and writes where possible.
+
+Unlike the implementation for arbitrary `bool` iterators, this is able to read
+from `rhs` and write into `self` an entire memory element at a time when both
+slices use the `Lsb0` or `Msb0` ordering. The two slices do not need to share a
+storage type, nor start at the same index within their first elements.
+
+If the two slices differ in length, only the bits of `self` that have a
+counterpart in `rhs` are modified.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let lhs = bits![mut Msb0, u16; 0, 0, 1, 1, 0, 0];
+let rhs = bits![Msb0, u8; 1, 0, 1, 0];
+*lhs |= rhs;
+assert_eq!(lhs, bits![1, 0, 1, 1, 0, 0]);
+```
+**/
+impl<'a, O, T, O2, T2> BitOrAssign<&'a BitSlice<O2, T2>> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	#[inline]
+	fn bitor_assign(&mut self, rhs: &'a BitSlice<O2, T2>) {
+		self.bitop_assign(rhs, BitOp::Or);
+	}
+}
+
+/** Performs a bitwise `^` between two bit-slices, using whole-element reads
+and writes where possible.
+
+Unlike the implementation for arbitrary `bool` iterators, this is able to read
+from `rhs` and write into `self` an entire memory element at a time when both
+slices use the `Lsb0` or `Msb0` ordering. The two slices do not need to share a
+storage type, nor start at the same index within their first elements.
+
+If the two slices differ in length, only the bits of `self` that have a
+counterpart in `rhs` are modified.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let lhs = bits![mut 0, 0, 1, 1];
+let rhs = bits![0, 1, 0, 1];
+*lhs ^= rhs;
+assert_eq!(lhs, bits![0, 1, 1, 0]);
+```
+**/
+impl<'a, O, T, O2, T2> BitXorAssign<&'a BitSlice<O2, T2>> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	#[inline]
+	fn bitxor_assign(&mut self, rhs: &'a BitSlice<O2, T2>) {
+		self.bitop_assign(rhs, BitOp::Xor);
+	}
+}
+
 impl<O, T> Index<usize> for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -103,6 +220,62 @@ where
 	}
 }
 
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Applies a bitwise operator between `self` and another bit-slice,
+	/// writing the result into `self`.
+	///
+	/// This is the shared implementation of the `Bit{And,Or,Xor}Assign`
+	/// operators with bit-slice right-hand sides.
+	pub(crate) fn bitop_assign<O2, T2>(
+		&mut self,
+		rhs: &BitSlice<O2, T2>,
+		op: BitOp,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let len = cmp::min(self.len(), rhs.len());
+		let (this, rest) = unsafe { self.split_at_unchecked_mut_noalias(len) };
+		let rhs = unsafe { rhs.get_unchecked(.. len) };
+
+		/* TODO(myrrlyn): Remove this when specialization stabilizes.
+
+		As in `.copy_from_bitslice()`, the `TypeId` checks select the batched
+		implementation only when both slices use the same provided ordering, so
+		the pointer casts are the identity function. The storage types are free
+		to differ, as `BitField` loads are independent of the storage type.
+		*/
+		if dvl::match_order::<O, Lsb0>() && dvl::match_order::<O2, Lsb0>() {
+			let this: &mut BitSlice<Lsb0, T> =
+				unsafe { &mut *(this as *mut _ as *mut _) };
+			let that: &BitSlice<Lsb0, T2> =
+				unsafe { &*(rhs as *const _ as *const _) };
+			this.sp_bitop_assign(that, op);
+		}
+		else if dvl::match_order::<O, Msb0>() && dvl::match_order::<O2, Msb0>()
+		{
+			let this: &mut BitSlice<Msb0, T> =
+				unsafe { &mut *(this as *mut _ as *mut _) };
+			let that: &BitSlice<Msb0, T2> =
+				unsafe { &*(rhs as *const _ as *const _) };
+			this.sp_bitop_assign(that, op);
+		}
+		else {
+			let mut iter = rhs.iter().by_val();
+			this.for_each(|_, bit| op.apply(bit, iter.next().unwrap_or(false)));
+		}
+
+		//  Bits of `self` with no counterpart in `rhs` are and-ed with `0`.
+		if op == BitOp::And {
+			rest.set_all(false);
+		}
+	}
+}
+
 /// Generate `Index`/`Mut` implementations for subslicing.
 macro_rules! index {
 	($($t:ty),+ $(,)?) => { $(
@@ -167,3 +340,108 @@ where
 		self
 	}
 }
+
+/** Shifts the contents of a bit-slice towards index `0`.
+
+This is [`BitSlice::shift_left`], except that shifting by the length of the
+bit-slice or more is not an error, and clears every bit.
+
+Shifts are defined in terms of indices, not of numeric significance, so that
+they behave the same under every [`BitOrder`]. Indices run left to right when a
+bit-slice is printed, so `<<` moves bits to the left of the rendered text, and
+fills in `0` bits on the right. When a bit-slice is read as a number, whether
+this multiplies or divides that number depends on which end of the number is at
+index `0`:
+
+- In an [`Msb0`] bit-slice over big-endian integer storage, such as an `[u8]`
+  buffer in network order, index `0` is the most significant bit and `<<`
+  matches the integer `<<`.
+- In an [`Lsb0`] bit-slice over little-endian integer storage, index `0` is the
+  least significant bit and `<<` matches the integer `>>`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut data = 0b0011_0001u8;
+let bits = data.view_bits_mut::<Msb0>();
+*bits <<= 2;
+assert_eq!(bits, bits![1, 1, 0, 0, 0, 1, 0, 0]);
+assert_eq!(data, 0b0011_0001 << 2);
+
+let mut data = 0b0011_0001u8;
+*data.view_bits_mut::<Lsb0>() <<= 4;
+assert_eq!(data, 0b0011_0001 >> 4);
+```
+
+[`BitOrder`]: crate::order::BitOrder
+[`BitSlice::shift_left`]: crate::slice::BitSlice::shift_left
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+**/
+impl<O, T> ShlAssign<usize> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn shl_assign(&mut self, by: usize) {
+		if by >= self.len() {
+			self.set_all(false);
+		}
+		else {
+			self.shift_left(by);
+		}
+	}
+}
+
+/** Shifts the contents of a bit-slice away from index `0`.
+
+This is [`BitSlice::shift_right`], except that shifting by the length of the
+bit-slice or more is not an error, and clears every bit.
+
+As with `<<=`, this moves bits to the right of the rendered text, and fills in
+`0` bits on the left. It matches the integer `>>` in an [`Msb0`] bit-slice over
+big-endian storage, and the integer `<<` in an [`Lsb0`] bit-slice over
+little-endian storage.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut data = 0b0011_0001u8;
+let bits = data.view_bits_mut::<Msb0>();
+*bits >>= 2;
+assert_eq!(bits, bits![0, 0, 0, 0, 1, 1, 0, 0]);
+assert_eq!(data, 0b0011_0001 >> 2);
+
+let mut data = 0b0011_0001u8;
+*data.view_bits_mut::<Lsb0>() >>= 2;
+assert_eq!(data, 0b0011_0001 << 2);
+
+let bits = bits![mut 1; 4];
+*bits >>= 10;
+assert!(bits.not_any());
+```
+
+[`BitSlice::shift_right`]: crate::slice::BitSlice::shift_right
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+**/
+impl<O, T> ShrAssign<usize> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn shr_assign(&mut self, by: usize) {
+		if by >= self.len() {
+			self.set_all(false);
+		}
+		else {
+			self.shift_right(by);
+		}
+	}
+}
@@ -0,0 +1,861 @@
+/*! Subslice search.
+
+This module finds occurrences of one bit-slice (the *needle*) inside another
+(the *haystack*). The two may have different type parameters; they are compared
+by the sequence of bit values, as `PartialEq` compares them.
+
+# Algorithm
+
+Both bit-slices are read as 64-bit words, with the bit at the lowest index of
+each word in its least significant position. For the [`Lsb0`] and [`Msb0`]
+orderings these words are loaded from memory with [`BitField`]; other orderings
+build them one bit at a time.
+
+The search tests 64 consecutive starting positions at once. It keeps one
+candidate mask, where bit `j` stands for the position `base + j`, and for each of
+the first (at most 64) bits of the needle it loads the haystack word that begins
+that many bits after `base`, and clears every candidate whose bit in that word
+differs from the needle bit. On irregular data about half of the candidates
+fall away at each step, so a block of 64 positions is usually rejected after a
+handful of word operations. Each surviving candidate is confirmed by comparing
+the rest of the needle one word at a time.
+
+Approximate matching cannot discard candidates on a single differing bit, so it
+tests each starting position in turn. It XORs the haystack words at that
+position with the needle words and counts the differing bits, stopping as soon
+as the count exceeds the allowed number of errors.
+
+[`BitField`]: crate::field::BitField
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+!*/
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+};
+
+use crate::{
+	field,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// The number of bits in a search word.
+const WORD: usize = 64;
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Finds the first occurrence of `needle` in the bit-slice.
+	///
+	/// `needle` may have different type parameters than `self`. The search
+	/// tests 64 starting positions at once, with one word operation for each
+	/// of the first 64 bits of `needle`, and then confirms each surviving
+	/// position one word at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The sequence of bits to find.
+	///
+	/// # Returns
+	///
+	/// The index at which the first copy of `needle` begins, or `None` if
+	/// `self` does not contain it. An empty `needle` is found at index `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 0];
+	/// assert_eq!(bits.find(bits![1, 1, 0]), Some(1));
+	/// assert_eq!(bits.find(bits![Msb0, u8; 0, 1, 1]), Some(0));
+	/// assert!(bits.find(bits![1, 1, 1]).is_none());
+	/// ```
+	#[inline]
+	pub fn find<O2, T2>(&self, needle: &BitSlice<O2, T2>) -> Option<usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		find_from(self, needle, 0)
+	}
+
+	/// Finds the last occurrence of `needle` in the bit-slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The sequence of bits to find.
+	///
+	/// # Returns
+	///
+	/// The index at which the last copy of `needle` begins, or `None` if
+	/// `self` does not contain it. An empty `needle` is found at
+	/// `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 0];
+	/// assert_eq!(bits.rfind(bits![1, 1, 0]), Some(4));
+	/// assert_eq!(bits.rfind(bits![]), Some(7));
+	/// ```
+	#[inline]
+	pub fn rfind<O2, T2>(&self, needle: &BitSlice<O2, T2>) -> Option<usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		rfind_before(self, needle, self.len())
+	}
+
+	/// Iterates over the starting indices of the non-overlapping occurrences
+	/// of `needle`, from the front.
+	///
+	/// After each match, the search resumes at the first bit past it. An empty
+	/// `needle` matches at every index from `0` through `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 1, 0, 1, 1];
+	/// let found = bits.match_indices(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 2, 5]);
+	/// ```
+	#[inline]
+	pub fn match_indices<'a, 'b, O2, T2>(
+		&'a self,
+		needle: &'b BitSlice<O2, T2>,
+	) -> MatchIndices<'a, 'b, O, T, O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		MatchIndices::new(self, needle, false)
+	}
+
+	/// Iterates over the starting indices of all occurrences of `needle`,
+	/// including those that overlap, from the front.
+	///
+	/// After each match, the search resumes at the bit after its start.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 1, 0, 1, 1];
+	/// let found =
+	///   bits.match_indices_overlapping(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 1, 2, 5]);
+	/// ```
+	#[inline]
+	pub fn match_indices_overlapping<'a, 'b, O2, T2>(
+		&'a self,
+		needle: &'b BitSlice<O2, T2>,
+	) -> MatchIndices<'a, 'b, O, T, O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		MatchIndices::new(self, needle, true)
+	}
+
+	/// Iterates over the subslices separated by non-overlapping occurrences of
+	/// `needle`.
+	///
+	/// This behaves like [`.split()`], with a bit-sequence separator rather
+	/// than a predicate. The separators are not included in the yielded
+	/// subslices. Separators at either end, or next to each other, produce
+	/// empty subslices. An empty `needle` does not split the bit-slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 1, 1, 0, 0, 0, 0];
+	/// let sep = bits![0, 0];
+	/// let mut iter = bits.split_pattern(sep);
+	/// assert_eq!(iter.next().unwrap(), bits![1]);
+	/// assert_eq!(iter.next().unwrap(), bits![1, 1]);
+	/// assert!(iter.next().unwrap().is_empty());
+	/// assert!(iter.next().unwrap().is_empty());
+	/// assert!(iter.next().is_none());
+	/// ```
+	///
+	/// [`.split()`]: Self::split
+	#[inline]
+	pub fn split_pattern<'a, 'b, O2, T2>(
+		&'a self,
+		needle: &'b BitSlice<O2, T2>,
+	) -> SplitPattern<'a, 'b, O, T, O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		SplitPattern {
+			haystack: self,
+			needle,
+			start: 0,
+			done: false,
+		}
+	}
+
+	/// Finds the first position at which `needle` occurs with at most
+	/// `max_errors` differing bits.
+	///
+	/// This is [`.approx_matches()`]`.next()`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The sequence of bits to find.
+	/// - `max_errors`: The largest Hamming distance accepted as a match.
+	///
+	/// # Returns
+	///
+	/// The index of the first match, and the number of bits in which it
+	/// differs from `needle`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1];
+	/// let sync = bits![1, 1, 1, 1];
+	/// assert!(bits.find(sync).is_none());
+	/// assert_eq!(bits.find_approx(sync, 1), Some((2, 1)));
+	/// ```
+	///
+	/// [`.approx_matches()`]: Self::approx_matches
+	#[inline]
+	pub fn find_approx<O2, T2>(
+		&self,
+		needle: &BitSlice<O2, T2>,
+		max_errors: usize,
+	) -> Option<(usize, usize)>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.approx_matches(needle, max_errors).next()
+	}
+
+	/// Finds the first position at which `needle` occurs with at most
+	/// `max_errors` differing bits, not counting the positions marked in
+	/// `ignore`.
+	///
+	/// This is [`.approx_matches_masked()`]`.next()`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The sequence of bits to find.
+	/// - `ignore`: A bit-slice as long as `needle`. Each set bit marks the
+	///   corresponding bit of `needle` as a don’t-care.
+	/// - `max_errors`: The largest Hamming distance accepted as a match.
+	///
+	/// # Returns
+	///
+	/// The index of the first match, and the number of compared bits in which
+	/// it differs from `needle`.
+	///
+	/// # Panics
+	///
+	/// This panics if `ignore` and `needle` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 0, 1, 1];
+	/// let needle = bits![1, 1, 1, 1];
+	/// let ignore = bits![0, 1, 0, 0];
+	/// assert_eq!(bits.find_approx_masked(needle, ignore, 0), Some((4, 0)));
+	/// ```
+	///
+	/// [`.approx_matches_masked()`]: Self::approx_matches_masked
+	#[inline]
+	pub fn find_approx_masked<O2, T2, O3, T3>(
+		&self,
+		needle: &BitSlice<O2, T2>,
+		ignore: &BitSlice<O3, T3>,
+		max_errors: usize,
+	) -> Option<(usize, usize)>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		O3: BitOrder,
+		T3: BitStore,
+	{
+		self.approx_matches_masked(needle, ignore, max_errors)
+			.next()
+	}
+
+	/// Iterates over every position at which `needle` occurs with at most
+	/// `max_errors` differing bits.
+	///
+	/// Each position is tested independently, so the matches may overlap. The
+	/// Hamming distance at a position is found by XOR-ing 64-bit words of the
+	/// bit-slice with words of `needle` and counting the set bits. The count
+	/// stops once it exceeds `max_errors`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The sequence of bits to find.
+	/// - `max_errors`: The largest Hamming distance accepted as a match.
+	///
+	/// # Returns
+	///
+	/// An iterator of `(index, distance)` pairs, in ascending order of index.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 1, 1, 1];
+	/// let sync = bits![1, 1, 1];
+	/// let found = bits.approx_matches(sync, 1).collect::<Vec<_>>();
+	/// assert_eq!(found, [(0, 1), (1, 1), (2, 1), (3, 0)]);
+	/// ```
+	#[inline]
+	pub fn approx_matches<'a, 'b, O2, T2>(
+		&'a self,
+		needle: &'b BitSlice<O2, T2>,
+		max_errors: usize,
+	) -> ApproxMatches<'a, 'b, O, T, O2, T2>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		ApproxMatches::new(self, needle, None, max_errors)
+	}
+
+	/// Iterates over every position at which `needle` occurs with at most
+	/// `max_errors` differing bits, not counting the positions marked in
+	/// `ignore`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The sequence of bits to find.
+	/// - `ignore`: A bit-slice as long as `needle`. Each set bit marks the
+	///   corresponding bit of `needle` as a don’t-care.
+	/// - `max_errors`: The largest Hamming distance accepted as a match.
+	///
+	/// # Returns
+	///
+	/// An iterator of `(index, distance)` pairs, in ascending order of index.
+	///
+	/// # Panics
+	///
+	/// This panics if `ignore` and `needle` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 1, 1, 0];
+	/// let needle = bits![1, 1, 1];
+	/// let ignore = bits![0, 1, 0];
+	/// let found = bits
+	///   .approx_matches_masked(needle, ignore, 0)
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(found, [(0, 0), (2, 0)]);
+	/// ```
+	#[inline]
+	pub fn approx_matches_masked<'a, 'b, O2, T2, O3, T3>(
+		&'a self,
+		needle: &'b BitSlice<O2, T2>,
+		ignore: &'b BitSlice<O3, T3>,
+		max_errors: usize,
+	) -> ApproxMatches<'a, 'b, O, T, O2, T2, O3, T3>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+		O3: BitOrder,
+		T3: BitStore,
+	{
+		assert_eq!(
+			needle.len(),
+			ignore.len(),
+			"Masked matching requires a mask of equal length"
+		);
+		ApproxMatches::new(self, needle, Some(ignore), max_errors)
+	}
+}
+
+/** An iterator over the starting indices of occurrences of a needle.
+
+This is produced by [`BitSlice::match_indices`] and
+[`BitSlice::match_indices_overlapping`].
+
+[`BitSlice::match_indices`]: crate::slice::BitSlice::match_indices
+[`BitSlice::match_indices_overlapping`]: crate::slice::BitSlice::match_indices_overlapping
+**/
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'a, 'b, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	/// The bit-slice being searched.
+	haystack: &'a BitSlice<O, T>,
+	/// The bit-slice being sought.
+	needle: &'b BitSlice<O2, T2>,
+	/// The lowest index at which the next match may begin.
+	cursor: usize,
+	/// The distance from the start of a match to the next search position.
+	step: usize,
+}
+
+impl<'a, 'b, O, T, O2, T2> MatchIndices<'a, 'b, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	/// Begins a search from the front of `haystack`.
+	fn new(
+		haystack: &'a BitSlice<O, T>,
+		needle: &'b BitSlice<O2, T2>,
+		overlapping: bool,
+	) -> Self {
+		let step = if overlapping {
+			1
+		}
+		else {
+			cmp::max(needle.len(), 1)
+		};
+		Self {
+			haystack,
+			needle,
+			cursor: 0,
+			step,
+		}
+	}
+}
+
+impl<O, T, O2, T2> Iterator for MatchIndices<'_, '_, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match find_from(self.haystack, self.needle, self.cursor) {
+			Some(pos) => {
+				self.cursor = pos + self.step;
+				Some(pos)
+			},
+			None => {
+				self.cursor = usize::MAX;
+				None
+			},
+		}
+	}
+}
+
+impl<O, T, O2, T2> FusedIterator for MatchIndices<'_, '_, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+}
+
+/** An iterator over the subslices of a bit-slice separated by a needle.
+
+This is produced by [`BitSlice::split_pattern`].
+
+[`BitSlice::split_pattern`]: crate::slice::BitSlice::split_pattern
+**/
+#[derive(Clone, Debug)]
+pub struct SplitPattern<'a, 'b, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	/// The bit-slice being split.
+	haystack: &'a BitSlice<O, T>,
+	/// The separator.
+	needle: &'b BitSlice<O2, T2>,
+	/// The index at which the next subslice begins.
+	start: usize,
+	/// Marks that the final subslice has been yielded.
+	done: bool,
+}
+
+impl<'a, O, T, O2, T2> Iterator for SplitPattern<'a, '_, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	type Item = &'a BitSlice<O, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let len = self.needle.len();
+		let found = if len == 0 {
+			None
+		}
+		else {
+			find_from(self.haystack, self.needle, self.start)
+		};
+		let start = self.start;
+		match found {
+			Some(pos) => {
+				self.start = pos + len;
+				Some(unsafe { self.haystack.get_unchecked(start .. pos) })
+			},
+			None => {
+				self.done = true;
+				Some(unsafe { self.haystack.get_unchecked(start ..) })
+			},
+		}
+	}
+}
+
+impl<O, T, O2, T2> FusedIterator for SplitPattern<'_, '_, O, T, O2, T2>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+}
+
+/** An iterator over the approximate occurrences of a needle.
+
+This is produced by [`BitSlice::approx_matches`] and
+[`BitSlice::approx_matches_masked`]. It yields the index of each match and its
+Hamming distance from the needle.
+
+[`BitSlice::approx_matches`]: crate::slice::BitSlice::approx_matches
+[`BitSlice::approx_matches_masked`]: crate::slice::BitSlice::approx_matches_masked
+**/
+#[derive(Clone, Debug)]
+pub struct ApproxMatches<'a, 'b, O, T, O2, T2, O3 = Lsb0, T3 = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+	O3: BitOrder,
+	T3: BitStore,
+{
+	/// The bit-slice being searched.
+	haystack: &'a BitSlice<O, T>,
+	/// The bit-slice being sought.
+	needle: &'b BitSlice<O2, T2>,
+	/// The don’t-care positions of the needle, if any.
+	ignore: Option<&'b BitSlice<O3, T3>>,
+	/// The first word of the needle.
+	pattern: u64,
+	/// The bits of `pattern` that are compared.
+	care: u64,
+	/// The largest accepted Hamming distance.
+	max_errors: usize,
+	/// The next index to test.
+	cursor: usize,
+}
+
+impl<'a, 'b, O, T, O2, T2, O3, T3> ApproxMatches<'a, 'b, O, T, O2, T2, O3, T3>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+	O3: BitOrder,
+	T3: BitStore,
+{
+	/// Begins a search from the front of `haystack`.
+	fn new(
+		haystack: &'a BitSlice<O, T>,
+		needle: &'b BitSlice<O2, T2>,
+		ignore: Option<&'b BitSlice<O3, T3>>,
+		max_errors: usize,
+	) -> Self {
+		let head = cmp::min(needle.len(), WORD);
+		let (pattern, care) = if head == 0 {
+			(0, 0)
+		}
+		else {
+			let ignored = ignore.map_or(0, |ignore| load_word(ignore, 0, head));
+			(load_word(needle, 0, head), !ignored)
+		};
+		Self {
+			haystack,
+			needle,
+			ignore,
+			pattern,
+			care,
+			max_errors,
+			cursor: 0,
+		}
+	}
+
+	/// Computes the Hamming distance between the needle and the haystack at
+	/// `pos`, if it is no more than `self.max_errors`.
+	fn distance(&self, pos: usize) -> Option<usize> {
+		let len = self.needle.len();
+		if len == 0 {
+			return Some(0);
+		}
+		let head = cmp::min(len, WORD);
+		let diff =
+			(load_word(self.haystack, pos, head) ^ self.pattern) & self.care;
+		let mut errors = diff.count_ones() as usize;
+		if errors > self.max_errors {
+			return None;
+		}
+		for offset in (WORD .. len).step_by(WORD) {
+			let width = cmp::min(WORD, len - offset);
+			let mut diff = load_word(self.haystack, pos + offset, width)
+				^ load_word(self.needle, offset, width);
+			if let Some(ignore) = self.ignore {
+				diff &= !load_word(ignore, offset, width);
+			}
+			errors += diff.count_ones() as usize;
+			if errors > self.max_errors {
+				return None;
+			}
+		}
+		Some(errors)
+	}
+}
+
+impl<O, T, O2, T2, O3, T3> Iterator
+	for ApproxMatches<'_, '_, O, T, O2, T2, O3, T3>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+	O3: BitOrder,
+	T3: BitStore,
+{
+	type Item = (usize, usize);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let last = self.haystack.len().checked_sub(self.needle.len())?;
+		while self.cursor <= last {
+			let pos = self.cursor;
+			self.cursor += 1;
+			if let Some(errors) = self.distance(pos) {
+				return Some((pos, errors));
+			}
+		}
+		None
+	}
+}
+
+impl<O, T, O2, T2, O3, T3> FusedIterator
+	for ApproxMatches<'_, '_, O, T, O2, T2, O3, T3>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+	O3: BitOrder,
+	T3: BitStore,
+{
+}
+
+/// Finds the first occurrence of `needle` in `haystack` that begins at or
+/// after `from`.
+pub(super) fn find_from<O, T, O2, T2>(
+	haystack: &BitSlice<O, T>,
+	needle: &BitSlice<O2, T2>,
+	from: usize,
+) -> Option<usize>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	let (len, width) = (haystack.len(), needle.len());
+	let last = len.checked_sub(width)?;
+	if from > last {
+		return None;
+	}
+	if width == 0 {
+		return Some(from);
+	}
+
+	let head = cmp::min(width, WORD);
+	let pattern = load_word(needle, 0, head);
+	let mut base = from;
+	while base <= last {
+		let count = cmp::min(WORD, last - base + 1);
+		let mut found = candidates(haystack, len, pattern, head, base, count);
+		while found != 0 {
+			let pos = base + found.trailing_zeros() as usize;
+			if matches_from(haystack, pos, needle, head) {
+				return Some(pos);
+			}
+			found &= found - 1;
+		}
+		base += WORD;
+	}
+	None
+}
+
+/// Finds the last occurrence of `needle` in `haystack` that ends at or before
+/// `end`.
+pub(super) fn rfind_before<O, T, O2, T2>(
+	haystack: &BitSlice<O, T>,
+	needle: &BitSlice<O2, T2>,
+	end: usize,
+) -> Option<usize>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	let width = needle.len();
+	let mut top = end.checked_sub(width)?;
+	if width == 0 {
+		return Some(end);
+	}
+
+	let head = cmp::min(width, WORD);
+	let pattern = load_word(needle, 0, head);
+	loop {
+		let base = top.saturating_sub(WORD - 1);
+		let count = top - base + 1;
+		let mut found = candidates(haystack, end, pattern, head, base, count);
+		while found != 0 {
+			let bit = WORD - 1 - found.leading_zeros() as usize;
+			if matches_from(haystack, base + bit, needle, head) {
+				return Some(base + bit);
+			}
+			found &= !(1 << bit);
+		}
+		top = base.checked_sub(1)?;
+	}
+}
+
+/// Tests if `needle` occurs in `haystack` at `pos`.
+///
+/// `haystack` must have at least `pos + needle.len()` bits.
+pub(super) fn matches_at<O, T, O2, T2>(
+	haystack: &BitSlice<O, T>,
+	pos: usize,
+	needle: &BitSlice<O2, T2>,
+) -> bool
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	matches_from(haystack, pos, needle, 0)
+}
+
+/// Computes which of the `count` starting positions beginning at `base` agree
+/// with the first `head` bits of the needle, held in `pattern`.
+///
+/// Only the first `len` bits of `haystack` are read. Bit `j` of the return
+/// value is set if the position `base + j` is a candidate.
+fn candidates<O, T>(
+	haystack: &BitSlice<O, T>,
+	len: usize,
+	pattern: u64,
+	head: usize,
+	base: usize,
+	count: usize,
+) -> u64
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let mut found = !0u64 >> (WORD - count);
+	for offset in 0 .. head {
+		if found == 0 {
+			break;
+		}
+		//  Positions that would read past `len` are never candidates, so the
+		//  zero-filled upper bits of a short word do not matter.
+		let start = base + offset;
+		let word = load_word(haystack, start, cmp::min(WORD, len - start));
+		found &= if pattern >> offset & 1 == 1 {
+			word
+		}
+		else {
+			!word
+		};
+	}
+	found
+}
+
+/// Tests if the bits of `needle` from `skip` onwards occur in `haystack` at
+/// `pos + skip`.
+fn matches_from<O, T, O2, T2>(
+	haystack: &BitSlice<O, T>,
+	pos: usize,
+	needle: &BitSlice<O2, T2>,
+	skip: usize,
+) -> bool
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	(skip .. needle.len()).step_by(WORD).all(|offset| {
+		let width = cmp::min(WORD, needle.len() - offset);
+		load_word(haystack, pos + offset, width)
+			== load_word(needle, offset, width)
+	})
+}
+
+/// Loads `width` bits, beginning at `start`, into a word with the bit at
+/// `start` in the least significant position.
+///
+/// `width` must be in `1 ..= 64`, and the range must be in bounds.
+#[inline]
+fn load_word<O, T>(bits: &BitSlice<O, T>, start: usize, width: usize) -> u64
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	field::load_indexed(unsafe { bits.get_unchecked(start .. start + width) })
+}
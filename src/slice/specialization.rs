@@ -8,17 +8,37 @@ inspect their type arguments to determine if specialization is even possible,
 and transmute generic slices into slices with concrete type arguments applied.
 !*/
 
-use core::ops::RangeBounds;
+use core::{
+	cmp,
+	hash::Hasher,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+		Not,
+		RangeBounds,
+	},
+};
 
 use funty::{
 	IsInteger,
 	IsNumber,
 };
+use tap::pipe::Pipe;
 
 use crate::{
+	access::BitAccess,
 	devel as dvl,
-	domain::Domain,
-	field::BitField,
+	domain::{
+		Domain,
+		DomainMut,
+	},
+	field::{
+		self,
+		BitField,
+	},
+	index::BitMask,
+	mem::BitRegister,
 	order::{
 		BitOrder,
 		Lsb0,
@@ -28,6 +48,56 @@ use crate::{
 	store::BitStore,
 };
 
+/// Selects the operator applied by the bitwise-assignment accelerators.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum BitOp {
+	/// `&=`
+	And,
+	/// `|=`
+	Or,
+	/// `^=`
+	Xor,
+	/// `&= !`, which clears every bit that is set in the right operand.
+	AndNot,
+}
+
+impl BitOp {
+	/// Applies the operator to two values, which may be `bool`s or registers.
+	#[inline]
+	pub(crate) fn apply<V>(self, lhs: V, rhs: V) -> V
+	where V: BitAnd<Output = V>
+			+ BitOr<Output = V>
+			+ BitXor<Output = V>
+			+ Not<Output = V> {
+		match self {
+			Self::And => lhs & rhs,
+			Self::Or => lhs | rhs,
+			Self::Xor => lhs ^ rhs,
+			Self::AndNot => lhs & !rhs,
+		}
+	}
+
+	/// Applies the operator to the bits of a partially-owned element that are
+	/// selected by `mask`, leaving all other bits untouched.
+	///
+	/// The other bits may be owned by other handles, so this must use the
+	/// single-instruction modifiers of `BitAccess` rather than a load/store
+	/// pair.
+	#[inline]
+	fn apply_masked<A>(self, elem: &A, mask: BitMask<A::Item>, rhs: A::Item)
+	where
+		A: BitAccess,
+		A::Item: BitRegister,
+	{
+		match self {
+			Self::And => elem.clear_bits(mask & !rhs),
+			Self::Or => elem.set_bits(mask & rhs),
+			Self::Xor => elem.invert_bits(mask & rhs),
+			Self::AndNot => elem.clear_bits(mask & rhs),
+		}
+	}
+}
+
 /** Order-specialized function implementations.
 
 These functions use [`BitField`] to provide batched load/store behavior.
@@ -110,6 +180,18 @@ where T: BitStore
 		}
 	}
 
+	/// Accelerates hashing by writing one loaded word per `usize` of bits.
+	///
+	/// Each word holds its first bit in the least significant position, so
+	/// that the written sequence is the same for all orderings.
+	pub(crate) fn sp_hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		let chunk_size = <usize as IsNumber>::BITS as usize;
+		for chunk in self.chunks(chunk_size) {
+			hasher.write_usize(chunk.load_le::<usize>());
+		}
+	}
+
 	/// Accelerates equality checking with batch loads.
 	pub(crate) fn sp_eq(&self, other: &Self) -> bool {
 		if self.len() != other.len() {
@@ -121,6 +203,132 @@ where T: BitStore
 			.all(|(a, b)| a.load_le::<usize>() == b.load_le::<usize>())
 	}
 
+	/// Accelerates `&=`, `|=`, and `^=` with batch loads from `rhs`.
+	///
+	/// `rhs` must have the same length as `self`, but may begin at any index
+	/// in its own memory. Each element of `self` is modified exactly once.
+	#[allow(clippy::op_ref)]
+	pub(crate) fn sp_bitop_assign<T2>(
+		&mut self,
+		rhs: &BitSlice<Lsb0, T2>,
+		op: BitOp,
+	) where
+		T2: BitStore,
+	{
+		debug_assert_eq!(self.len(), rhs.len(), "Operands must be equal length");
+		if self.is_empty() {
+			return;
+		}
+
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let val = rhs
+					.load_le::<T::Mem>()
+					.pipe(|val| val << &(head.value() as usize));
+				op.apply_masked(elem, Lsb0::mask(head, tail), val);
+			},
+			DomainMut::Region { head, body, tail } => {
+				let mut rhs = rhs;
+				if let Some((head, elem)) = head {
+					let (this, rest) = unsafe {
+						rhs.split_at_unchecked(bits - head.value() as usize)
+					};
+					let val = this
+						.load_le::<T::Mem>()
+						.pipe(|val| val << &(head.value() as usize));
+					op.apply_masked(elem, Lsb0::mask(head, None), val);
+					rhs = rest;
+				}
+
+				let (this, rest) =
+					unsafe { rhs.split_at_unchecked(body.len() * bits) };
+				for (elem, chunk) in body.iter_mut().zip(this.chunks_exact(bits))
+				{
+					let val =
+						op.apply(elem.load_value(), chunk.load_le::<T::Mem>());
+					elem.store_value(val);
+				}
+
+				if let Some((elem, tail)) = tail {
+					let val = rest.load_le::<T::Mem>();
+					op.apply_masked(elem, Lsb0::mask(None, tail), val);
+				}
+			},
+		}
+	}
+
+	/// Walks `self` and `rhs` together, one element of `self` at a time.
+	///
+	/// `func` receives the live bits of each element of `self`, and the bits
+	/// of `rhs` at the same indices, loaded and shifted into the same
+	/// positions. All dead bits in both values are cleared. The walk stops
+	/// early if `func` returns `false`, and the return value reports whether
+	/// the walk completed.
+	#[allow(clippy::op_ref)]
+	pub(crate) fn sp_zip_elements<T2, F>(
+		&self,
+		rhs: &BitSlice<Lsb0, T2>,
+		mut func: F,
+	) -> bool
+	where
+		T2: BitStore,
+		F: FnMut(T::Mem, T::Mem) -> bool,
+	{
+		debug_assert_eq!(self.len(), rhs.len(), "Operands must be equal length");
+		if self.is_empty() {
+			return true;
+		}
+
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				let mask = Lsb0::mask(head, tail);
+				let val = rhs
+					.load_le::<T::Mem>()
+					.pipe(|val| val << &(head.value() as usize));
+				func((mask & elem.load_value()).value(), (mask & val).value())
+			},
+			Domain::Region { head, body, tail } => {
+				let mut rhs = rhs;
+				if let Some((head, elem)) = head {
+					let (this, rest) = unsafe {
+						rhs.split_at_unchecked(bits - head.value() as usize)
+					};
+					let mask = Lsb0::mask(head, None);
+					let val = this
+						.load_le::<T::Mem>()
+						.pipe(|val| val << &(head.value() as usize));
+					if !func(
+						(mask & elem.load_value()).value(),
+						(mask & val).value(),
+					) {
+						return false;
+					}
+					rhs = rest;
+				}
+
+				let (this, rest) =
+					unsafe { rhs.split_at_unchecked(body.len() * bits) };
+				for (elem, chunk) in body.iter().zip(this.chunks_exact(bits)) {
+					if !func(elem.load_value(), chunk.load_le::<T::Mem>()) {
+						return false;
+					}
+				}
+
+				if let Some((elem, tail)) = tail {
+					let mask = Lsb0::mask(None, tail);
+					let val = rest.load_le::<T::Mem>();
+					return func(
+						(mask & elem.load_value()).value(),
+						(mask & val).value(),
+					);
+				}
+				true
+			},
+		}
+	}
+
 	/// Seeks the index of the first `1` bit in the bit-slice.
 	pub(crate) fn sp_iter_ones_first(&self) -> Option<usize> {
 		let mut accum = 0;
@@ -190,7 +398,8 @@ where T: BitStore
 						(Lsb0::mask(None, tail) & elem.load_value()).value();
 					let dead_bits =
 						T::Mem::BITS as usize - tail.value() as usize;
-					out -= val.leading_zeros() as usize - dead_bits;
+					out = out
+						.wrapping_sub(val.leading_zeros() as usize - dead_bits);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -198,7 +407,9 @@ where T: BitStore
 
 				for elem in body.iter().rev() {
 					let val = elem.load_value();
-					out -= val.leading_zeros() as usize;
+					//  The cursor runs past zero when the search fails, so it
+					//  wraps.
+					out = out.wrapping_sub(val.leading_zeros() as usize);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -287,7 +498,8 @@ where T: BitStore
 						(Lsb0::mask(None, tail) & !elem.load_value()).value();
 					let dead_bits =
 						T::Mem::BITS as usize - tail.value() as usize;
-					out -= val.leading_zeros() as usize - dead_bits;
+					out = out
+						.wrapping_sub(val.leading_zeros() as usize - dead_bits);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -295,7 +507,9 @@ where T: BitStore
 
 				for elem in body.iter().rev() {
 					let val = !elem.load_value();
-					out -= val.leading_zeros() as usize;
+					//  The cursor runs past zero when the search fails, so it
+					//  wraps.
+					out = out.wrapping_sub(val.leading_zeros() as usize);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -314,6 +528,93 @@ where T: BitStore
 			},
 		}
 	}
+
+	/// Accelerates reversal by swapping word-sized chunks from each end, with
+	/// each chunk reversed by a single `reverse_bits` instruction.
+	pub(crate) fn sp_reverse(&mut self) {
+		let chunk_size = <usize as IsNumber>::BITS as usize;
+		let (mut lo, mut hi) = (0, self.len());
+		while hi - lo > 1 {
+			let width = cmp::min(chunk_size, (hi - lo) / 2);
+			//  Reversal moves the live bits of a short chunk to the top of the
+			//  register, so they must be shifted back down.
+			let shamt = chunk_size - width;
+			unsafe {
+				let front =
+					self.get_unchecked(lo .. lo + width).load_le::<usize>();
+				let back =
+					self.get_unchecked(hi - width .. hi).load_le::<usize>();
+				self.get_unchecked_mut(lo .. lo + width)
+					.store_le::<usize>(back.reverse_bits() >> shamt);
+				self.get_unchecked_mut(hi - width .. hi)
+					.store_le::<usize>(front.reverse_bits() >> shamt);
+			}
+			lo += width;
+			hi -= width;
+		}
+	}
+
+	/// Accelerates `gather` by extracting the selected bits of each 64-bit
+	/// chunk at once.
+	///
+	/// `mask` must have the same length as `self`, and `dst` must have the
+	/// length of `mask.count_ones()`.
+	#[cfg(feature = "alloc")]
+	pub(crate) fn sp_gather<T2, T3>(
+		&self,
+		mask: &BitSlice<Lsb0, T2>,
+		dst: &mut BitSlice<Lsb0, T3>,
+	) where
+		T2: BitStore,
+		T3: BitStore,
+	{
+		debug_assert_eq!(self.len(), mask.len(), "Mask must be equal length");
+		let mut pos = 0;
+		for (src, sel) in self.chunks(64).zip(mask.chunks(64)) {
+			let sel = sel.load_le::<u64>();
+			let count = sel.count_ones() as usize;
+			if count == 0 {
+				continue;
+			}
+			let val = pext(src.load_le::<u64>(), sel);
+			unsafe { dst.get_unchecked_mut(pos .. pos + count) }
+				.store_le::<u64>(val);
+			pos += count;
+		}
+	}
+
+	/// Accelerates `scatter` by depositing into the selected bits of each
+	/// 64-bit chunk at once.
+	///
+	/// `mask` must have the same length as `self`, and `src` must have at
+	/// least `mask.count_ones()` bits.
+	pub(crate) fn sp_scatter<T2, T3>(
+		&mut self,
+		mask: &BitSlice<Lsb0, T2>,
+		src: &BitSlice<Lsb0, T3>,
+	) where
+		T2: BitStore,
+		T3: BitStore,
+	{
+		debug_assert_eq!(self.len(), mask.len(), "Mask must be equal length");
+		let mut pos = 0;
+		for (dst, sel) in
+			unsafe { self.chunks_mut(64).remove_alias() }.zip(mask.chunks(64))
+		{
+			let sel = sel.load_le::<u64>();
+			let count = sel.count_ones() as usize;
+			if count == 0 {
+				continue;
+			}
+			let val = pdep(
+				unsafe { src.get_unchecked(pos .. pos + count) }.load_le(),
+				sel,
+			);
+			let old = dst.load_le::<u64>();
+			dst.store_le::<u64>(old & !sel | val);
+			pos += count;
+		}
+	}
 }
 
 /** Order-specialized function implementations.
@@ -380,6 +681,18 @@ where T: BitStore
 		}
 	}
 
+	/// Accelerates hashing by writing one loaded word per `usize` of bits.
+	///
+	/// Each word holds its first bit in the least significant position, so
+	/// that the written sequence is the same for all orderings.
+	pub(crate) fn sp_hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		let chunk_size = <usize as IsNumber>::BITS as usize;
+		for chunk in self.chunks(chunk_size) {
+			hasher.write_usize(field::load_indexed(chunk));
+		}
+	}
+
 	/// Accelerates equality checking with batch loads.
 	pub(crate) fn sp_eq(&self, other: &Self) -> bool {
 		if self.len() != other.len() {
@@ -391,6 +704,132 @@ where T: BitStore
 			.all(|(a, b)| a.load_be::<usize>() == b.load_be::<usize>())
 	}
 
+	/// Accelerates `&=`, `|=`, and `^=` with batch loads from `rhs`.
+	///
+	/// `rhs` must have the same length as `self`, but may begin at any index
+	/// in its own memory. Each element of `self` is modified exactly once.
+	#[allow(clippy::op_ref)]
+	pub(crate) fn sp_bitop_assign<T2>(
+		&mut self,
+		rhs: &BitSlice<Msb0, T2>,
+		op: BitOp,
+	) where
+		T2: BitStore,
+	{
+		debug_assert_eq!(self.len(), rhs.len(), "Operands must be equal length");
+		if self.is_empty() {
+			return;
+		}
+
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let val = rhs
+					.load_be::<T::Mem>()
+					.pipe(|val| val << &(bits - tail.value() as usize));
+				op.apply_masked(elem, Msb0::mask(head, tail), val);
+			},
+			DomainMut::Region { head, body, tail } => {
+				let mut rhs = rhs;
+				if let Some((head, elem)) = head {
+					let (this, rest) = unsafe {
+						rhs.split_at_unchecked(bits - head.value() as usize)
+					};
+					let val = this.load_be::<T::Mem>();
+					op.apply_masked(elem, Msb0::mask(head, None), val);
+					rhs = rest;
+				}
+
+				let (this, rest) =
+					unsafe { rhs.split_at_unchecked(body.len() * bits) };
+				for (elem, chunk) in body.iter_mut().zip(this.chunks_exact(bits))
+				{
+					let val =
+						op.apply(elem.load_value(), chunk.load_be::<T::Mem>());
+					elem.store_value(val);
+				}
+
+				if let Some((elem, tail)) = tail {
+					let val = rest
+						.load_be::<T::Mem>()
+						.pipe(|val| val << &(bits - tail.value() as usize));
+					op.apply_masked(elem, Msb0::mask(None, tail), val);
+				}
+			},
+		}
+	}
+
+	/// Walks `self` and `rhs` together, one element of `self` at a time.
+	///
+	/// `func` receives the live bits of each element of `self`, and the bits
+	/// of `rhs` at the same indices, loaded and shifted into the same
+	/// positions. All dead bits in both values are cleared. The walk stops
+	/// early if `func` returns `false`, and the return value reports whether
+	/// the walk completed.
+	#[allow(clippy::op_ref)]
+	pub(crate) fn sp_zip_elements<T2, F>(
+		&self,
+		rhs: &BitSlice<Msb0, T2>,
+		mut func: F,
+	) -> bool
+	where
+		T2: BitStore,
+		F: FnMut(T::Mem, T::Mem) -> bool,
+	{
+		debug_assert_eq!(self.len(), rhs.len(), "Operands must be equal length");
+		if self.is_empty() {
+			return true;
+		}
+
+		let bits = <T::Mem as IsNumber>::BITS as usize;
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				let mask = Msb0::mask(head, tail);
+				let val = rhs
+					.load_be::<T::Mem>()
+					.pipe(|val| val << &(bits - tail.value() as usize));
+				func((mask & elem.load_value()).value(), (mask & val).value())
+			},
+			Domain::Region { head, body, tail } => {
+				let mut rhs = rhs;
+				if let Some((head, elem)) = head {
+					let (this, rest) = unsafe {
+						rhs.split_at_unchecked(bits - head.value() as usize)
+					};
+					let mask = Msb0::mask(head, None);
+					let val = this.load_be::<T::Mem>();
+					if !func(
+						(mask & elem.load_value()).value(),
+						(mask & val).value(),
+					) {
+						return false;
+					}
+					rhs = rest;
+				}
+
+				let (this, rest) =
+					unsafe { rhs.split_at_unchecked(body.len() * bits) };
+				for (elem, chunk) in body.iter().zip(this.chunks_exact(bits)) {
+					if !func(elem.load_value(), chunk.load_be::<T::Mem>()) {
+						return false;
+					}
+				}
+
+				if let Some((elem, tail)) = tail {
+					let mask = Msb0::mask(None, tail);
+					let val = rest
+						.load_be::<T::Mem>()
+						.pipe(|val| val << &(bits - tail.value() as usize));
+					return func(
+						(mask & elem.load_value()).value(),
+						(mask & val).value(),
+					);
+				}
+				true
+			},
+		}
+	}
+
 	/// Seeks the index of the first `1` bit in the bit-slice.
 	pub(crate) fn sp_iter_ones_first(&self) -> Option<usize> {
 		let mut accum = 0;
@@ -460,7 +899,8 @@ where T: BitStore
 						(Msb0::mask(None, tail) & elem.load_value()).value();
 					let dead_bits =
 						T::Mem::BITS as usize - tail.value() as usize;
-					out -= val.trailing_zeros() as usize - dead_bits;
+					out = out
+						.wrapping_sub(val.trailing_zeros() as usize - dead_bits);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -468,7 +908,9 @@ where T: BitStore
 
 				for elem in body.iter().rev() {
 					let val = elem.load_value();
-					out -= val.trailing_zeros() as usize;
+					//  The cursor runs past zero when the search fails, so it
+					//  wraps.
+					out = out.wrapping_sub(val.trailing_zeros() as usize);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -556,7 +998,8 @@ where T: BitStore
 						(Msb0::mask(None, tail) & !elem.load_value()).value();
 					let dead_bits =
 						T::Mem::BITS as usize - tail.value() as usize;
-					out -= val.trailing_zeros() as usize - dead_bits;
+					out = out
+						.wrapping_sub(val.trailing_zeros() as usize - dead_bits);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -564,7 +1007,9 @@ where T: BitStore
 
 				for elem in body.iter().rev() {
 					let val = !elem.load_value();
-					out -= val.trailing_zeros() as usize;
+					//  The cursor runs past zero when the search fails, so it
+					//  wraps.
+					out = out.wrapping_sub(val.trailing_zeros() as usize);
 					if val != T::Mem::ZERO {
 						return Some(out);
 					}
@@ -583,4 +1028,180 @@ where T: BitStore
 			},
 		}
 	}
+
+	/// Accelerates reversal by swapping word-sized chunks from each end, with
+	/// each chunk reversed by a single `reverse_bits` instruction.
+	pub(crate) fn sp_reverse(&mut self) {
+		let chunk_size = <usize as IsNumber>::BITS as usize;
+		let (mut lo, mut hi) = (0, self.len());
+		while hi - lo > 1 {
+			let width = cmp::min(chunk_size, (hi - lo) / 2);
+			//  Reversal moves the live bits of a short chunk to the top of the
+			//  register, so they must be shifted back down.
+			let shamt = chunk_size - width;
+			unsafe {
+				let front =
+					self.get_unchecked(lo .. lo + width).load_be::<usize>();
+				let back =
+					self.get_unchecked(hi - width .. hi).load_be::<usize>();
+				self.get_unchecked_mut(lo .. lo + width)
+					.store_be::<usize>(back.reverse_bits() >> shamt);
+				self.get_unchecked_mut(hi - width .. hi)
+					.store_be::<usize>(front.reverse_bits() >> shamt);
+			}
+			lo += width;
+			hi -= width;
+		}
+	}
+
+	/// Accelerates `gather` by extracting the selected bits of each 64-bit
+	/// chunk at once.
+	///
+	/// `mask` must have the same length as `self`, and `dst` must have the
+	/// length of `mask.count_ones()`.
+	#[cfg(feature = "alloc")]
+	pub(crate) fn sp_gather<T2, T3>(
+		&self,
+		mask: &BitSlice<Msb0, T2>,
+		dst: &mut BitSlice<Msb0, T3>,
+	) where
+		T2: BitStore,
+		T3: BitStore,
+	{
+		debug_assert_eq!(self.len(), mask.len(), "Mask must be equal length");
+		let mut pos = 0;
+		for (src, sel) in self.chunks(64).zip(mask.chunks(64)) {
+			let sel = sel.load_be::<u64>();
+			let count = sel.count_ones() as usize;
+			if count == 0 {
+				continue;
+			}
+			let val = pext(src.load_be::<u64>(), sel);
+			unsafe { dst.get_unchecked_mut(pos .. pos + count) }
+				.store_be::<u64>(val);
+			pos += count;
+		}
+	}
+
+	/// Accelerates `scatter` by depositing into the selected bits of each
+	/// 64-bit chunk at once.
+	///
+	/// `mask` must have the same length as `self`, and `src` must have at
+	/// least `mask.count_ones()` bits.
+	pub(crate) fn sp_scatter<T2, T3>(
+		&mut self,
+		mask: &BitSlice<Msb0, T2>,
+		src: &BitSlice<Msb0, T3>,
+	) where
+		T2: BitStore,
+		T3: BitStore,
+	{
+		debug_assert_eq!(self.len(), mask.len(), "Mask must be equal length");
+		let mut pos = 0;
+		for (dst, sel) in
+			unsafe { self.chunks_mut(64).remove_alias() }.zip(mask.chunks(64))
+		{
+			let sel = sel.load_be::<u64>();
+			let count = sel.count_ones() as usize;
+			if count == 0 {
+				continue;
+			}
+			let val = pdep(
+				unsafe { src.get_unchecked(pos .. pos + count) }.load_be(),
+				sel,
+			);
+			let old = dst.load_be::<u64>();
+			dst.store_be::<u64>(old & !sel | val);
+			pos += count;
+		}
+	}
+}
+
+/// Extracts the bits of `src` selected by `mask`, and packs them into the
+/// least significant bits of the return value, as the BMI2 `pext` instruction
+/// does.
+#[inline]
+#[cfg(feature = "alloc")]
+fn pext(src: u64, mask: u64) -> u64 {
+	#[cfg(all(feature = "bmi2", target_arch = "x86_64"))]
+	{
+		if bmi2::detected() {
+			return unsafe { bmi2::pext(src, mask) };
+		}
+	}
+	let (mut mask, mut out, mut bit) = (mask, 0, 1);
+	//  Visit only the set bits of the mask, lowest first.
+	while mask != 0 {
+		if src & mask & mask.wrapping_neg() != 0 {
+			out |= bit;
+		}
+		mask &= mask - 1;
+		bit <<= 1;
+	}
+	out
+}
+
+/// Spreads the least significant bits of `src` into the bits selected by
+/// `mask`, clearing all others, as the BMI2 `pdep` instruction does.
+#[inline]
+fn pdep(src: u64, mask: u64) -> u64 {
+	#[cfg(all(feature = "bmi2", target_arch = "x86_64"))]
+	{
+		if bmi2::detected() {
+			return unsafe { bmi2::pdep(src, mask) };
+		}
+	}
+	let (mut mask, mut out, mut bit) = (mask, 0, 1);
+	while mask != 0 {
+		if src & bit != 0 {
+			out |= mask & mask.wrapping_neg();
+		}
+		mask &= mask - 1;
+		bit <<= 1;
+	}
+	out
+}
+
+/// Hardware bit extraction and deposit, for processors with BMI2.
+#[cfg(all(feature = "bmi2", target_arch = "x86_64"))]
+mod bmi2 {
+	use core::arch::x86_64 as arch;
+
+	/// Tests whether the instructions can be used.
+	///
+	/// This is constant when the crate is compiled for a processor that has
+	/// BMI2, and otherwise asks the processor at run time, which requires
+	/// `std`.
+	#[inline]
+	pub(super) fn detected() -> bool {
+		#[cfg(target_feature = "bmi2")]
+		{
+			true
+		}
+		#[cfg(all(not(target_feature = "bmi2"), feature = "std"))]
+		{
+			std::is_x86_feature_detected!("bmi2")
+		}
+		#[cfg(all(not(target_feature = "bmi2"), not(feature = "std")))]
+		{
+			false
+		}
+	}
+
+	/// # Safety
+	///
+	/// The processor must have BMI2.
+	#[cfg(feature = "alloc")]
+	#[target_feature(enable = "bmi2")]
+	pub(super) unsafe fn pext(src: u64, mask: u64) -> u64 {
+		arch::_pext_u64(src, mask)
+	}
+
+	/// # Safety
+	///
+	/// The processor must have BMI2.
+	#[target_feature(enable = "bmi2")]
+	pub(super) unsafe fn pdep(src: u64, mask: u64) -> u64 {
+		arch::_pdep_u64(src, mask)
+	}
 }
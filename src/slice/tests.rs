@@ -4,7 +4,10 @@
 
 use tap::conv::TryConv;
 
-use crate::prelude::*;
+use crate::{
+	devel as dvl,
+	prelude::*,
+};
 
 #[test]
 fn construction() {
@@ -401,6 +404,112 @@ fn invert() {
 	assert_eq!(data, [0x3C, 0xF0, 0xFF, 0x0F]);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn bitops_batched() {
+	let src = (0 .. 300).map(|n| n % 3 == 0 || n % 7 == 0);
+	let lsb = src.clone().collect::<BitVec<Lsb0, u8>>();
+	let msb = src.collect::<BitVec<Msb0, u32>>();
+
+	//  Enclave, head/body/tail, and mismatched heads across both storage types
+	for &(to, from, len) in &[
+		(3, 1, 4),
+		(0, 0, 256),
+		(5, 13, 200),
+		(17, 2, 250),
+		(1, 9, 33),
+	] {
+		let mut and_l = bitvec![Lsb0, u16; 1; 300];
+		let mut or_l = bitvec![Lsb0, u16; 0; 300];
+		let mut xor_l = and_l.clone();
+		and_l[to ..][.. len] &= &lsb[from ..][.. len];
+		or_l[to ..][.. len] |= &lsb[from ..][.. len];
+		xor_l[to ..][.. len] ^= &lsb[from ..][.. len];
+		assert_eq!(and_l[to ..][.. len], lsb[from ..][.. len]);
+		assert_eq!(or_l[to ..][.. len], lsb[from ..][.. len]);
+		assert_eq!(!xor_l, or_l);
+		assert!(and_l[.. to].all() && and_l[to + len ..].all());
+		assert!(or_l[.. to].not_any() && or_l[to + len ..].not_any());
+
+		let mut and_m = bitvec![Msb0, u8; 1; 300];
+		let mut or_m = bitvec![Msb0, u8; 0; 300];
+		let mut xor_m = and_m.clone();
+		and_m[to ..][.. len] &= &msb[from ..][.. len];
+		or_m[to ..][.. len] |= &msb[from ..][.. len];
+		xor_m[to ..][.. len] ^= &msb[from ..][.. len];
+		assert_eq!(and_m[to ..][.. len], msb[from ..][.. len]);
+		assert_eq!(or_m[to ..][.. len], msb[from ..][.. len]);
+		assert_eq!(!xor_m, or_m);
+		assert!(and_m[.. to].all() && and_m[to + len ..].all());
+		assert!(or_m[.. to].not_any() && or_m[to + len ..].not_any());
+	}
+
+	//  Mismatched orderings walk bit by bit.
+	let mut bits = bitvec![Lsb0, u8; 0; 300];
+	bits |= &msb[..];
+	assert_eq!(bits, msb);
+
+	//  A short right-hand side clears the remainder only for `&`.
+	let mut bits = bitvec![Msb0, u8; 1; 20];
+	bits ^= &msb[.. 10];
+	bits &= &msb[.. 10];
+	assert!(bits.not_any());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn set_algebra() {
+	let a = (0 .. 200).map(|n| n % 3 == 0).collect::<BitVec<Lsb0, u8>>();
+	let b = (0 .. 200)
+		.map(|n| n % 6 == 0)
+		.collect::<BitVec<Lsb0, u32>>();
+	let c = (0 .. 200)
+		.map(|n| n % 6 == 0)
+		.collect::<BitVec<Msb0, u16>>();
+	let d = (0 .. 200).map(|n| n % 2 == 1).collect::<BitVec<Msb0, u8>>();
+
+	//  Same-ordering operands take the batched path; mixed orderings do not.
+	for &(x, y) in &[(0, 0), (3, 9), (6, 0), (1, 7)] {
+		let a = &a[x ..];
+		let (b, c) = (&b[y ..], &c[y ..]);
+		let naive = |f: fn(bool, bool) -> bool| {
+			let len = core::cmp::max(a.len(), b.len());
+			(0 .. len)
+				.filter(|&i| {
+					f(
+						a.get(i).map_or(false, |bit| *bit),
+						b.get(i).map_or(false, |bit| *bit),
+					)
+				})
+				.count()
+		};
+		assert_eq!(a.intersection_count(b), naive(|l, r| l & r));
+		assert_eq!(a.intersection_count(c), naive(|l, r| l & r));
+		assert_eq!(a.union_count(b), naive(|l, r| l | r));
+		assert_eq!(a.union_count(c), naive(|l, r| l | r));
+		assert_eq!(a.difference_count(b), naive(|l, r| l & !r));
+		assert_eq!(a.difference_count(c), naive(|l, r| l & !r));
+		assert_eq!(a.hamming_distance(b), naive(|l, r| l ^ r));
+		assert_eq!(a.hamming_distance(c), naive(|l, r| l ^ r));
+	}
+
+	assert!(b.is_subset_of(&a));
+	assert!(c.is_subset_of(&a));
+	assert!(a.is_superset_of(&c));
+	assert!(!a.is_subset_of(&b));
+	assert!(!b.is_subset_of(&a[1 ..]));
+	assert!(c.is_disjoint(&d));
+	assert!(!a.is_disjoint(&d));
+	assert!(d[.. 100].is_disjoint(&d[1 ..]));
+
+	//  Indices past the end of the shorter operand are absent from its set.
+	assert!(!a.is_subset_of(&b[.. 3]));
+	assert!(a[.. 3].is_subset_of(&b));
+	assert!(a[.. 1].is_disjoint(&d));
+	assert_eq!(a.difference_count(&b[.. 0]), a.count_ones());
+	assert_eq!(a[.. 0].union_count(&d), d.count_ones());
+}
+
 #[test]
 fn rotate() {
 	let bits = bits![mut 0, 1, 0, 0, 1, 0];
@@ -450,6 +559,28 @@ fn unspecialized() {
 	//  Dodge the memcpy accelerant
 	bits[.. 8].copy_from_bitslice(&bits![Swizzle, u8; 0; 9][1 ..]);
 	assert_eq!(bits, [0u8, !0].view_bits::<Swizzle>());
+
+	#[cfg(feature = "std")]
+	{
+		use std::{
+			collections::hash_map::DefaultHasher,
+			hash::{
+				Hash,
+				Hasher,
+			},
+		};
+
+		let swizzled = bits![Swizzle, u16; 0, 1, 1, 0, 1, 0, 0, 0, 1, 1];
+		let plain = swizzled.iter().by_val().collect::<BitVec<Msb0, u8>>();
+		let (mut a, mut b) = (DefaultHasher::new(), DefaultHasher::new());
+		swizzled[1 ..].hash(&mut a);
+		plain[1 ..].hash(&mut b);
+		assert_eq!(a.finish(), b.finish());
+
+		let needle = &plain[3 .. 7];
+		assert_eq!(swizzled.find(needle), Some(3));
+		assert_eq!(swizzled.rfind(needle), plain.rfind(needle));
+	}
 }
 
 #[test]
@@ -783,6 +914,7 @@ fn specialized_iter_ones() {
 	assert!(bits[17 .. 23].sp_iter_ones_last().is_none());
 	assert!(bits[12 .. 28].sp_iter_ones_first().is_none());
 	assert!(bits[12 .. 28].sp_iter_ones_last().is_none());
+	assert!(bits[16 .. 24].sp_iter_ones_last().is_none());
 
 	assert_eq!(bits[3 ..].sp_iter_ones_first(), Some(1));
 	assert_eq!(bits[5 ..].sp_iter_ones_first(), Some(5));
@@ -801,6 +933,7 @@ fn specialized_iter_ones() {
 	assert!(bits[17 .. 23].sp_iter_ones_last().is_none());
 	assert!(bits[14 .. 26].sp_iter_ones_first().is_none());
 	assert!(bits[14 .. 26].sp_iter_ones_last().is_none());
+	assert!(bits[16 .. 24].sp_iter_ones_last().is_none());
 
 	assert_eq!(bits[2 ..].sp_iter_ones_first(), Some(1));
 	assert_eq!(bits[4 ..].sp_iter_ones_first(), Some(9));
@@ -815,6 +948,132 @@ fn specialized_iter_ones() {
 	assert_eq!(bits[2 .. 6].sp_iter_ones_last(), Some(1));
 }
 
+#[test]
+fn next_prev_search() {
+	let data = [0x08u8, 0x20, 0, 0x04, 0x08];
+
+	let bits = data.view_bits::<Msb0>();
+	assert_eq!(bits.next_one(0), Some(4));
+	assert_eq!(bits.next_one(5), Some(10));
+	assert_eq!(bits.next_one(11), Some(29));
+	assert_eq!(bits.next_one(36), Some(36));
+	assert!(bits.next_one(37).is_none());
+	assert!(bits.next_one(40).is_none());
+	assert_eq!(bits.prev_one(40), Some(36));
+	assert_eq!(bits.prev_one(36), Some(29));
+	assert_eq!(bits.prev_one(29), Some(10));
+	assert!(bits.prev_one(4).is_none());
+	assert!(bits.prev_one(0).is_none());
+
+	assert_eq!(bits.next_zero(4), Some(5));
+	assert_eq!(bits.prev_zero(5), Some(3));
+
+	let inv = !data[0];
+	let bits = inv.view_bits::<Lsb0>();
+	assert_eq!(bits.next_zero(0), Some(3));
+	assert!(bits.next_zero(4).is_none());
+	assert_eq!(bits.prev_zero(8), Some(3));
+	assert!(bits.prev_zero(3).is_none());
+
+	let bits = data.view_bits::<Lsb0>();
+	for from in 0 ..= bits.len() {
+		assert_eq!(
+			bits.next_one(from),
+			bits.iter_ones().find(|&idx| idx >= from)
+		);
+		assert_eq!(
+			bits.next_zero(from),
+			bits.iter_zeros().find(|&idx| idx >= from)
+		);
+		assert_eq!(
+			bits.prev_one(from),
+			bits.iter_ones().rev().find(|&idx| idx < from)
+		);
+		assert_eq!(
+			bits.prev_zero(from),
+			bits.iter_zeros().rev().find(|&idx| idx < from)
+		);
+	}
+}
+
+#[test]
+fn find_run() {
+	let mut data = [!0u16; 4];
+	let bits = data.view_bits_mut::<Msb0>();
+	bits.set_range(3 .. 5, false);
+	bits.set_range(14 .. 35, false);
+	bits.set_range(40 ..= 47, false);
+
+	assert_eq!(bits.find_run(false, 0), Some(0));
+	assert_eq!(bits.find_run(false, 2), Some(3));
+	assert_eq!(bits.find_run(false, 3), Some(14));
+	assert_eq!(bits.find_run(false, 21), Some(14));
+	assert!(bits.find_run(false, 22).is_none());
+	assert_eq!(bits.find_run(true, 3), Some(0));
+	assert_eq!(bits.find_run(true, 16), Some(48));
+	assert!(bits.find_run(true, 17).is_none());
+
+	assert_eq!(bits.find_run_from(false, 2, 4), Some(14));
+	assert_eq!(bits.find_run_from(false, 8, 30), Some(40));
+	assert_eq!(bits.find_run_from(false, 0, 64), Some(64));
+	assert!(bits.find_run_from(false, 0, 65).is_none());
+	assert!(bits.find_run_from(true, 1, 64).is_none());
+
+	assert_eq!(bits.find_run_aligned(false, 2, 4), Some(16));
+	assert_eq!(bits.find_run_aligned(false, 16, 16), Some(16));
+	assert_eq!(bits.find_run_aligned(false, 8, 8), Some(16));
+	assert_eq!(bits.find_run_aligned(false, 8, 32), None);
+	assert_eq!(bits.find_run_aligned(true, 3, 64), Some(0));
+	assert!(bits.find_run_aligned(true, 4, 64).is_none());
+
+	for len in 0 .. 24 {
+		let expected = (0 ..= bits.len() - len)
+			.find(|&idx| bits[idx .. idx + len].not_any());
+		assert_eq!(bits.find_run(false, len), expected, "{}", len);
+		let aligned = (0 ..= bits.len() - len)
+			.step_by(4)
+			.find(|&idx| bits[idx .. idx + len].not_any());
+		assert_eq!(bits.find_run_aligned(false, len, 4), aligned, "{}", len);
+	}
+
+	bits.set_range(.., true);
+	assert!(bits.all());
+	bits.set_range(60 .., false);
+	assert_eq!(bits.find_run(false, 4), Some(60));
+}
+
+#[test]
+fn fill_toggle_range() {
+	let mut data = [0u32; 5];
+	let bits = data.view_bits_mut::<Lsb0>();
+	bits.fill_range(5 .. 150, true);
+	assert_eq!(bits.count_ones(), 145);
+	assert!(bits[.. 5].not_any());
+	assert!(bits[150 ..].not_any());
+
+	bits.toggle_range(3 .. 7);
+	assert_eq!(bits[.. 8], bits![0, 0, 0, 1, 1, 0, 0, 1]);
+	bits.toggle_range(64 .. 96);
+	assert_eq!(bits.as_raw_slice()[2], 0);
+	bits.toggle_range(..);
+	assert_eq!(bits.count_ones(), 47);
+
+	let mut data = [0x5A5A_A5A5u32; 2];
+	let bits = data.view_bits_mut::<Msb0>();
+	bits.toggle_range(7 .. 7);
+	assert_eq!(bits.as_raw_slice(), [0x5A5A_A5A5; 2]);
+	bits.toggle_range(4 .. 40);
+	assert_eq!(bits.as_raw_slice(), [0x55A5_5A5A, 0xA55A_A5A5]);
+	bits.fill_range(4 .. 40, false);
+	assert_eq!(bits.as_raw_slice(), [0x5000_0000, 0x005A_A5A5]);
+}
+
+#[test]
+#[should_panic]
+fn set_range_oob() {
+	bits![mut 0; 8].set_range(4 .. 9, true);
+}
+
 #[test]
 fn specialized_iter_zeros() {
 	let data = [!0x08u8, !0x20, !0, !0x04, !0x08];
@@ -824,6 +1083,7 @@ fn specialized_iter_zeros() {
 	assert!(bits[17 .. 23].sp_iter_zeros_last().is_none());
 	assert!(bits[12 .. 28].sp_iter_zeros_first().is_none());
 	assert!(bits[12 .. 28].sp_iter_zeros_last().is_none());
+	assert!(bits[16 .. 24].sp_iter_zeros_last().is_none());
 
 	assert_eq!(
 		bits[3 ..].sp_iter_zeros_first(),
@@ -847,6 +1107,7 @@ fn specialized_iter_zeros() {
 	assert!(bits[17 .. 23].sp_iter_zeros_last().is_none());
 	assert!(bits[14 .. 26].sp_iter_zeros_first().is_none());
 	assert!(bits[14 .. 26].sp_iter_zeros_last().is_none());
+	assert!(bits[16 .. 24].sp_iter_zeros_last().is_none());
 
 	assert_eq!(bits[2 ..].sp_iter_zeros_first(), Some(1));
 	assert_eq!(bits[4 ..].sp_iter_zeros_first(), Some(9));
@@ -861,6 +1122,61 @@ fn specialized_iter_zeros() {
 	assert_eq!(bits[2 .. 6].sp_iter_zeros_last(), Some(1));
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn iter_runs() {
+	#[cfg(not(feature = "std"))]
+	use alloc::vec::Vec;
+
+	assert!(bits![].iter_runs().next().is_none());
+	assert_eq!(bits![].count_runs(), 0);
+
+	let data = [0x0Fu8, 0xF0, 0xFF, 0x00, 0x81];
+	check(&data.view_bits::<Msb0>()[3 ..]);
+	check(&data.view_bits::<Lsb0>()[3 ..]);
+	check(&data.view_bits::<Lsb0>()[16 .. 24]);
+
+	fn check<O>(bits: &BitSlice<O, u8>)
+	where O: BitOrder {
+		//  Build the expected runs one bit at a time.
+		let mut expected = Vec::<(bool, core::ops::Range<usize>)>::new();
+		for (idx, bit) in bits.iter().by_val().enumerate() {
+			match expected.last_mut() {
+				Some((val, run)) if *val == bit => run.end = idx + 1,
+				_ => expected.push((bit, idx .. idx + 1)),
+			}
+		}
+
+		assert_eq!(bits.iter_runs().collect::<Vec<_>>(), expected);
+		assert_eq!(bits.count_runs(), expected.len());
+
+		let mut backwards = bits.iter_runs().rev().collect::<Vec<_>>();
+		backwards.reverse();
+		assert_eq!(backwards, expected);
+
+		//  Meet in the middle from both ends.
+		let mut runs = bits.iter_runs();
+		let mut front = Vec::new();
+		let mut back = Vec::new();
+		while let Some(run) = runs.next() {
+			front.push(run);
+			match runs.next_back() {
+				Some(run) => back.push(run),
+				None => break,
+			}
+		}
+		front.extend(back.into_iter().rev());
+		assert_eq!(front, expected);
+
+		assert_eq!(
+			BitVec::<Lsb0, u16>::from_runs(
+				bits.iter_runs().map(|(bit, run)| (bit, run.len()))
+			),
+			bits
+		);
+	}
+}
+
 #[cfg(feature = "alloc")]
 mod format {
 	#[cfg(not(feature = "std"))]
@@ -1099,3 +1415,278 @@ mod format {
 		);
 	}
 }
+
+#[test]
+fn reverse_chunks() {
+	let data = [0x0123_4567_89AB_CDEFu64, 0xFEDC_BA98_7654_3210];
+
+	let mut lsb = data;
+	lsb.view_bits_mut::<Lsb0>().reverse();
+	assert_eq!(lsb, [0x084C_2A6E_195D_3B7F, 0xF7B3_D591_E6A2_C480]);
+
+	let mut lsb = data;
+	lsb.view_bits_mut::<Lsb0>()[9 .. 120].reverse();
+	assert_eq!(lsb, [0x1098_54DC_32BA_77EF, 0xFE67_AB23_CD45_8900]);
+
+	let mut msb = data;
+	msb.view_bits_mut::<Msb0>()[9 .. 120].reverse();
+	assert_eq!(msb, [0x0126_1537_0CAE_9DBF, 0xFBD9_EAC8_F351_6210]);
+
+	let mut bytes = [0x5Au8, 0x0F, 0xC3];
+	bytes.view_bits_mut::<Msb0>()[1 .. 21].reverse();
+	assert_eq!(bytes, [0x0F, 0xC1, 0x6B]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn gather_scatter() {
+	let data = [0x0123_4567_89AB_CDEFu64, 0xFEDC_BA98_7654_3210];
+	let masks = [0xFF00_FF00_FF00_FF00u64, 0x0000_0000_FFFF_FFFF];
+
+	let bits = data.view_bits::<Lsb0>();
+	let mask = masks.view_bits::<Lsb0>();
+	let expected = 0x7654_3210_0145_89CDu64;
+	assert_eq!(bits.gather(mask), expected.view_bits::<Lsb0>());
+	assert_eq!(bits[8 ..].gather(&mask[8 ..]), expected.view_bits::<Lsb0>());
+
+	let bits = data.view_bits::<Msb0>();
+	let mask = masks.view_bits::<Msb0>();
+	let expected = 0x0145_89CD_7654_3210u64;
+	assert_eq!(bits.gather(mask), expected.view_bits::<Msb0>());
+
+	let mut dst = [!0u64; 2];
+	dst.view_bits_mut::<Msb0>()
+		.scatter(mask, expected.view_bits::<Msb0>());
+	assert_eq!(dst, [0x01FF_45FF_89FF_CDFF, 0xFFFF_FFFF_7654_3210]);
+
+	//  Mismatched orderings use the bit-by-bit path.
+	let bits = bits![Msb0, u8; 1, 0, 1, 1, 0, 0, 1, 0];
+	let mask = bits![1, 1, 0, 1, 0, 0, 0, 1];
+	assert_eq!(bits.gather(mask), bits![1, 0, 1, 0]);
+
+	let mut dst = 0u8;
+	dst.view_bits_mut::<Msb0>().scatter(mask, bits![1, 1, 1, 0]);
+	assert_eq!(dst, 0b1101_0000);
+}
+
+#[test]
+#[should_panic]
+fn scatter_short_source() {
+	bits![mut 0; 4].scatter(bits![1, 1, 0, 1], bits![1, 1]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn hash_consistency() {
+	use std::{
+		collections::hash_map::DefaultHasher,
+		hash::{
+			Hash,
+			Hasher,
+		},
+	};
+
+	fn hash_of<O, T>(bits: &BitSlice<O, T>) -> u64
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut hasher = DefaultHasher::new();
+		bits.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	//  The same seventy bits, in four different memory layouts.
+	let lsb = [0x35u8; 9];
+	let msb = [0xACu8; 9];
+	let wide = [0xACACu16; 5];
+	let shifted = [0xA9u8; 10];
+	let bits = &lsb.view_bits::<Lsb0>()[.. 70];
+	let expected = hash_of(bits);
+
+	assert_eq!(msb.view_bits::<Msb0>()[.. 70], bits);
+	assert_eq!(hash_of(&msb.view_bits::<Msb0>()[.. 70]), expected);
+	assert_eq!(wide.view_bits::<Msb0>()[.. 70], bits);
+	assert_eq!(hash_of(&wide.view_bits::<Msb0>()[.. 70]), expected);
+	assert_eq!(shifted.view_bits::<Lsb0>()[3 .. 73], bits);
+	assert_eq!(hash_of(&shifted.view_bits::<Lsb0>()[3 .. 73]), expected);
+
+	//  The length separates bit-slices that differ only in trailing zeros.
+	assert_ne!(hash_of(bits![0; 3]), hash_of(bits![0; 4]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn subslice_search() {
+	//  Sparse data, so that long needles still recur.
+	let base = dvl::xorshift(0x2545_F491_4F6C_DD1D)
+		.take(400)
+		.map(|word| word % 7 == 0)
+		.collect::<BitVec<Lsb0, usize>>();
+
+	fn naive<O, T>(hay: &BitSlice<O, T>, needle: &BitSlice) -> Vec<usize>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		(0 ..= hay.len().saturating_sub(needle.len()))
+			.filter(|&idx| {
+				hay.len() >= needle.len()
+					&& hay[idx .. idx + needle.len()] == needle
+			})
+			.collect()
+	}
+
+	for &(start, end) in &[(0, 400), (3, 397), (64, 200)] {
+		let hay = &base[start .. end];
+		let msb = hay.iter().by_val().collect::<BitVec<Msb0, u8>>();
+		let local = hay.iter().by_val().collect::<BitVec<LocalBits, u16>>();
+		for &(from, to) in &[(5, 8), (10, 30), (0, 64), (40, 110), (100, 101)] {
+			let needle = &base[from .. to];
+			let all = naive(hay, needle);
+			let mut disjoint = Vec::new();
+			for &idx in &all {
+				if disjoint.last().map_or(true, |&l| idx >= l + needle.len()) {
+					disjoint.push(idx);
+				}
+			}
+
+			assert_eq!(hay.find(needle), all.first().copied());
+			assert_eq!(msb.find(needle), all.first().copied());
+			assert_eq!(local.find(needle), all.first().copied());
+			assert_eq!(hay.rfind(needle), all.last().copied());
+			assert_eq!(
+				msb[1 ..].rfind(needle),
+				naive(&msb[1 ..], needle).last().copied()
+			);
+			assert_eq!(
+				hay.match_indices_overlapping(needle).collect::<Vec<_>>(),
+				all
+			);
+			assert_eq!(
+				msb.match_indices_overlapping(needle).collect::<Vec<_>>(),
+				all
+			);
+			assert_eq!(hay.match_indices(needle).collect::<Vec<_>>(), disjoint);
+			assert_eq!(hay.contains(needle), !all.is_empty());
+
+			let needle_msb =
+				needle.iter().by_val().collect::<BitVec<Msb0, u32>>();
+			assert_eq!(hay.find(&needle_msb), all.first().copied());
+			assert_eq!(local.rfind(&needle_msb), all.last().copied());
+
+			let pieces = msb.split_pattern(needle).collect::<Vec<_>>();
+			assert_eq!(pieces.len(), disjoint.len() + 1);
+			let mut rebuilt = BitVec::<Lsb0, usize>::new();
+			for (idx, piece) in pieces.iter().enumerate() {
+				if idx > 0 {
+					rebuilt.extend_from_bitslice(needle);
+				}
+				rebuilt.extend_from_bitslice(piece);
+			}
+			assert_eq!(rebuilt, hay);
+
+			assert!(hay.starts_with(&hay[.. to - from]));
+			assert!(msb.ends_with(&hay[hay.len() - (to - from) ..]));
+
+			//  Approximate matching, against distances counted one bit at a
+			//  time. The bits after the needle serve as its mask.
+			let max = needle.len() / 8;
+			let mask = &base[to .. to + needle.len()];
+			let within = |masked: bool| {
+				(0 ..= hay.len() - needle.len())
+					.map(|idx| {
+						let dist = (0 .. needle.len())
+							.filter(|&bit| {
+								!(masked && mask[bit])
+									&& hay[idx + bit] != needle[bit]
+							})
+							.count();
+						(idx, dist)
+					})
+					.filter(|&(_, dist)| dist <= max)
+					.collect::<Vec<_>>()
+			};
+			let plain = within(false);
+			let masked = within(true);
+			assert_eq!(
+				hay.approx_matches(needle, max).collect::<Vec<_>>(),
+				plain
+			);
+			assert_eq!(
+				msb.approx_matches(needle, max).collect::<Vec<_>>(),
+				plain
+			);
+			assert_eq!(local.find_approx(needle, max), plain.first().copied());
+			assert_eq!(
+				msb.approx_matches_masked(needle, mask, max)
+					.collect::<Vec<_>>(),
+				masked
+			);
+			assert_eq!(
+				hay.find_approx_masked(&needle_msb, mask, max),
+				masked.first().copied()
+			);
+		}
+	}
+
+	let bits = bits![0, 1, 1];
+	assert_eq!(bits.find(bits![]), Some(0));
+	assert_eq!(bits.rfind(bits![]), Some(3));
+	assert_eq!(bits.match_indices(bits![]).collect::<Vec<_>>(), [
+		0, 1, 2, 3
+	]);
+	assert_eq!(bits.split_pattern(bits![]).collect::<Vec<_>>(), [bits]);
+	assert!(bits.find(bits![0, 1, 1, 0]).is_none());
+	assert!(bits![].rfind(bits![1]).is_none());
+	assert!(bits![].split_pattern(bits![1]).next().unwrap().is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn approx_search() {
+	#[cfg(not(feature = "std"))]
+	use alloc::vec::Vec;
+
+	//  Distances from the needle at each start: 2 0 2 1 1 3 2 0.
+	let hay = bits![0, 1, 1, 0, 1, 0, 0, 1, 1, 0];
+	let needle = bits![1, 1, 0];
+	assert_eq!(hay.approx_matches(needle, 1).collect::<Vec<_>>(), [
+		(1, 0),
+		(3, 1),
+		(4, 1),
+		(7, 0)
+	]);
+	assert_eq!(hay.find_approx(needle, 0), Some((1, 0)));
+	assert_eq!(hay.find_approx(needle, 3), Some((0, 2)));
+
+	//  Ignoring the last needle bit: 1 0 1 1 1 2 1 0.
+	let mask = bits![0, 0, 1];
+	assert_eq!(
+		hay.approx_matches_masked(needle, mask, 0)
+			.collect::<Vec<_>>(),
+		[(1, 0), (7, 0)]
+	);
+	assert_eq!(hay.find_approx_masked(needle, mask, 1), Some((0, 1)));
+
+	let mut data = [0u8; 10];
+	let hay = data.view_bits_mut::<Msb0>();
+	hay.set(70, true);
+	hay.set(72, true);
+	assert_eq!(hay.approx_matches(bits![1; 4], 2).collect::<Vec<_>>(), [
+		(69, 2),
+		(70, 2)
+	]);
+
+	let bits = bits![0, 1, 1];
+	assert_eq!(bits.find_approx(bits![], 0), Some((0, 0)));
+	assert_eq!(bits.approx_matches(bits![], 0).count(), 4);
+	assert!(bits.find_approx(bits![0; 4], 4).is_none());
+	assert_eq!(bits.find_approx(bits![1, 0, 0], 3), Some((0, 3)));
+}
+
+#[test]
+#[should_panic]
+fn approx_mask_length() {
+	bits![0; 8].find_approx_masked(bits![1; 3], bits![0; 4], 0);
+}
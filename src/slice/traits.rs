@@ -492,8 +492,19 @@ fmt!(Octal, b'0', b'o', 3);
 fmt!(LowerHex, b'a', b'x', 4);
 fmt!(UpperHex, b'A', b'x', 4);
 
-/// Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
-#[cfg(not(tarpaulin_include))]
+/** Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
+
+The bits are written one `usize` at a time, followed by the length of the
+bit-slice. Each word holds the next `usize::BITS` bits of the bit-slice, with
+the bit at the lowest index in the least significant position, and the final
+word is zero-extended. This depends only on the sequence of bit values, so
+bit-slices that compare equal through the cross-type `PartialEq` implementation
+hash identically regardless of their ordering, storage type, or alignment in
+memory.
+
+For the `Lsb0` and `Msb0` orderings, each word is loaded from memory with
+`BitField`. Other orderings build each word one bit at a time.
+**/
 impl<O, T> Hash for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -501,9 +512,30 @@ where
 {
 	fn hash<H>(&self, hasher: &mut H)
 	where H: Hasher {
-		for bit in self {
-			hasher.write_u8(*bit as u8);
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			this.sp_hash(hasher);
+		}
+		else if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			this.sp_hash(hasher);
+		}
+		else {
+			let chunk_size = <usize as IsNumber>::BITS as usize;
+			for chunk in self.chunks(chunk_size) {
+				let word = chunk
+					.iter()
+					.by_val()
+					.enumerate()
+					.fold(0usize, |word, (idx, bit)| {
+						word | (bit as usize) << idx
+					});
+				hasher.write_usize(word);
+			}
 		}
+		hasher.write_usize(self.len());
 	}
 }
 
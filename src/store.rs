@@ -135,7 +135,17 @@ means that `u8`, `u16`, `u32`, and `usize` unconditionally implement `BitStore`,
 but `u64` will only do so on 64-bit targets. This is a necessary restriction of
 `bitvec` internals. Please comment on [Issue #76] if this affects you.
 
+`u128` does not implement `BitStore` on any target. Its alias-safe and [`Cell`]
+forms require [`radium`] implementations that do not exist, and the bit-slice
+pointer encoding requires each storage type to be aligned to its own width,
+which `u128` is not on every target. `u128` is still a [`BitMemory`] type, so
+fields up to 128 bits wide can be moved through [`BitField`] on any storage
+type.
+
 [Issue #76]: https://github.com/myrrlyn/bitvec/issues/76
+[`BitField`]: crate::field::BitField
+[`BitMemory`]: crate::mem::BitMemory
+[`radium`]: radium
 [atom]: core::sync::atomic
 [atomic]: core::sync::atomic
 [`BitSlice`]: crate::slice::BitSlice
@@ -302,6 +302,46 @@ where
 		out
 	}
 
+	/// Constructs a `BitVec` from a sequence of runs of identical bits.
+	///
+	/// This is the reverse of [`BitSlice::iter_runs`]: each run is appended to
+	/// the bit-vector in order. Adjacent runs may have the same value, and
+	/// empty runs are permitted.
+	///
+	/// # Parameters
+	///
+	/// - `runs`: A sequence of `(bit, len)` pairs, each describing `len`
+	///   consecutive bits set to `bit`.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` whose length is the sum of all the run lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<Lsb0, u8>::from_runs(vec![(false, 2), (true, 3), (false, 1)]);
+	/// assert_eq!(bv, bits![0, 0, 1, 1, 1, 0]);
+	///
+	/// let copy = BitVec::<Lsb0, u8>::from_runs(
+	///   bv.iter_runs().map(|(bit, run)| (bit, run.len())),
+	/// );
+	/// assert_eq!(bv, copy);
+	/// ```
+	///
+	/// [`BitSlice::iter_runs`]: crate::slice::BitSlice::iter_runs
+	#[inline]
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = (bool, usize)> {
+		let mut out = Self::new();
+		for (bit, len) in runs {
+			out.resize(out.len() + len, bit);
+		}
+		out
+	}
+
 	/// Copies the contents of a [`BitSlice`] into a new allocation.
 	///
 	/// This is an exact copy: the newly-created bit-vector is initialized with
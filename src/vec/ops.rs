@@ -1,6 +1,7 @@
 //! Port of the `Vec<T>` operator implementations.
 
 use core::{
+	borrow::Borrow,
 	mem::ManuallyDrop,
 	ops::{
 		BitAnd,
@@ -14,10 +15,15 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
 use crate::{
+	boxed::BitBox,
 	order::BitOrder,
 	slice::BitSlice,
 	store::BitStore,
@@ -99,6 +105,79 @@ where
 	}
 }
 
+/** Computes a bitwise operator between two bit-slices into a new bit-vector.
+
+The result has the ordering and (unaliased) storage type of `lhs`, and the
+length of the longer operand. The shorter operand is treated as if it were
+extended with `0` bits to match the longer: an `&` clears the excess, and an `|`
+or `^` copies it from the longer operand.
+
+The result is produced by copying `lhs` into a new allocation, and then applying
+the bit-slice assignment operator, so it has the same element-wise acceleration.
+**/
+#[allow(clippy::type_complexity)]
+fn bitop_into<O, T, O2, T2>(
+	lhs: &BitSlice<O, T>,
+	rhs: &BitSlice<O2, T2>,
+	op: fn(&mut BitSlice<O, T::Unalias>, &BitSlice<O2, T2>),
+) -> BitVec<O, T::Unalias>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	let mut out = lhs.to_bitvec();
+	if out.len() < rhs.len() {
+		out.resize(rhs.len(), false);
+	}
+	op(out.as_mut_bitslice(), rhs);
+	out
+}
+
+/// Generates non-destructive binary operators between borrowed bit-slices.
+macro_rules! bitop_into {
+	($($lhs:ident & $rhs:ident),+ $(,)?) => { $(
+		bitop_into!(@impl $lhs, $rhs, BitAnd, bitand, &=);
+		bitop_into!(@impl $lhs, $rhs, BitOr, bitor, |=);
+		bitop_into!(@impl $lhs, $rhs, BitXor, bitxor, ^=);
+	)+ };
+	(@impl $lhs:ident, $rhs:ident, $trait:ident, $func:ident, $op:tt) => {
+		/// Computes the operator into a new `BitVec`, zero-extending the
+		/// shorter operand to the length of the longer.
+		impl<'a, 'b, O, T, O2, T2> $trait<&'b $rhs<O2, T2>> for &'a $lhs<O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+			O2: BitOrder,
+			T2: BitStore,
+		{
+			type Output = BitVec<O, T::Unalias>;
+
+			#[inline]
+			fn $func(self, rhs: &'b $rhs<O2, T2>) -> Self::Output {
+				bitop_into(
+					Borrow::<BitSlice<O, T>>::borrow(self),
+					Borrow::<BitSlice<O2, T2>>::borrow(rhs),
+					|this, that| *this $op that,
+				)
+			}
+		}
+	};
+}
+
+bitop_into!(
+	BitSlice & BitSlice,
+	BitSlice & BitVec,
+	BitSlice & BitBox,
+	BitVec & BitSlice,
+	BitVec & BitVec,
+	BitVec & BitBox,
+	BitBox & BitSlice,
+	BitBox & BitVec,
+	BitBox & BitBox,
+);
+
 impl<O, T> Deref for BitVec<O, T>
 where
 	O: BitOrder,
@@ -174,3 +253,63 @@ where
 		self
 	}
 }
+
+/// Shifts the contents towards index `0`, keeping the length unchanged.
+///
+/// See the [`BitSlice`] implementation for the effect under each ordering.
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+impl<O, T, Rhs> Shl<Rhs> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+impl<O, T, Rhs> ShlAssign<Rhs> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShlAssign<Rhs>,
+{
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+/// Shifts the contents away from index `0`, keeping the length unchanged.
+///
+/// See the [`BitSlice`] implementation for the effect under each ordering.
+///
+/// [`BitSlice`]: crate::slice::BitSlice
+impl<O, T, Rhs> Shr<Rhs> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+impl<O, T, Rhs> ShrAssign<Rhs> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: ShrAssign<Rhs>,
+{
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
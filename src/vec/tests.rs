@@ -301,6 +301,41 @@ fn ops() {
 	assert_eq!(e, bits![0, 1, 1, 0]);
 	let f = !e;
 	assert_eq!(f, bits![1, 0, 0, 1]);
+
+	let mut bv = bitvec![Lsb0, u8; 1, 1, 0, 1, 0];
+	bv <<= 1;
+	assert_eq!(bv, bits![1, 0, 1, 0, 0]);
+	bv >>= 2;
+	assert_eq!(bv, bits![0, 0, 1, 0, 1]);
+	let bb = bitbox![Lsb0, u8; 1; 5] << 3;
+	assert_eq!(bb, bits![1, 1, 0, 0, 0]);
+	let bb = bb >> 4;
+	assert_eq!(bb, bits![0, 0, 0, 0, 1]);
+	assert!((bv << 5).not_any());
+}
+
+#[test]
+fn borrowed_ops() {
+	let a = bits![Msb0, u8; 0, 0, 1, 1];
+	let b = bitvec![Msb0, u16; 0, 1, 0, 1];
+	let c = bitbox![Lsb0, u32; 1, 1, 1, 1, 0, 1];
+
+	let and: BitVec<Msb0, u8> = a & &b;
+	assert_eq!(and, bits![0, 0, 0, 1]);
+	assert_eq!(&b | a, bits![0, 1, 1, 1]);
+	assert_eq!(&b ^ a, bits![0, 1, 1, 0]);
+
+	//  The shorter operand is zero-extended.
+	assert_eq!(a & &c, bits![0, 0, 1, 1, 0, 0]);
+	assert_eq!(&c & a, bits![0, 0, 1, 1, 0, 0]);
+	assert_eq!(a | &c, bits![1, 1, 1, 1, 0, 1]);
+	assert_eq!(&b ^ &c, bits![1, 0, 1, 0, 0, 1]);
+	assert_eq!(&c ^ &b, bits![1, 0, 1, 0, 0, 1]);
+
+	//  Misaligned operands still produce their own bits.
+	let d = &a[1 ..] | &c[3 ..];
+	assert_eq!(d, bits![1, 1, 1]);
+	assert!(a & BitSlice::<Lsb0, usize>::empty() == bits![0; 4]);
 }
 
 #[test]